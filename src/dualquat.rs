@@ -0,0 +1,169 @@
+
+use vec3::*;
+use vec4::*;
+use mat4::*;
+use nonsquare::*;
+use quat::*;
+use traits::*;
+use functions::dot;
+
+use std::ops::*;
+
+/// A dual quaternion `real + dual * e` where `e * e = 0`.
+/// Unit dual quaternions represent rigid transforms, a
+/// rotation followed by a translation.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DualQuat<T: Sized> {
+	pub real: Quat<T>,
+	pub dual: Quat<T>
+}
+
+impl<T: Sized> DualQuat<T> {
+	pub fn new(real: Quat<T>, dual: Quat<T>) -> Self {
+		Self { real, dual }
+	}
+}
+
+impl<T: Sized + Clone + Zero + One> DualQuat<T> {
+	/// Returns the dual quaternion for the identity transform.
+	pub fn identity() -> Self {
+		Self::new(Quat::identity(), Quat::new(T::zero(), T::zero(), T::zero(), T::zero()))
+	}
+}
+
+impl<T> DualQuat<T>
+	where T: Sized + Copy + Zero + One + Neg<Output = T> + Add<Output = T>
+		+ Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Creates the transform that applies `rotation`, which
+	/// must be a unit quaternion, and then translates by
+	/// `translation`.
+	pub fn from_rotation_translation(rotation: Quat<T>, translation: Vec3<T>) -> Self {
+		let half = T::one() / (T::one() + T::one());
+		let t = Quat::from_parts(translation, T::zero());
+
+		Self::new(rotation, t * rotation * half)
+	}
+
+	/// Creates a transform that only translates.
+	pub fn from_translation(translation: Vec3<T>) -> Self {
+		Self::from_rotation_translation(Quat::identity(), translation)
+	}
+
+	/// Returns the rotation part of a unit dual quaternion.
+	pub fn rotation(&self) -> Quat<T> {
+		self.real
+	}
+
+	/// Returns the translation part of a unit dual quaternion.
+	pub fn translation(&self) -> Vec3<T> {
+		let t = (self.dual * self.real.conjugate()).vector();
+
+		t + t
+	}
+
+	/// Returns the dual quaternion with both parts conjugated.
+	/// For unit dual quaternions this is the inverse transform.
+	pub fn conjugate(&self) -> Self {
+		Self::new(self.real.conjugate(), self.dual.conjugate())
+	}
+
+	/// Returns the multiplicative inverse of the dual quaternion.
+	pub fn inverse(&self) -> Self {
+		let real = self.real.inverse();
+
+		Self::new(real, -(real * self.dual * real))
+	}
+
+	/// Applies the transform of a unit dual quaternion to a point.
+	pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+		self.real * point + self.translation()
+	}
+
+	/// Applies the transform of a unit dual quaternion to a
+	/// direction. This only rotates the vector.
+	pub fn transform_vector(&self, vector: Vec3<T>) -> Vec3<T> {
+		self.real * vector
+	}
+
+	/// Returns the 4x4 matrix of the transform.
+	pub fn to_mat4(&self) -> Mat4<T> {
+		let m = Mat4::from_mat3(self.real.to_mat3());
+		let t = self.translation();
+
+		Mat4::from_cols([ m[0], m[1], m[2], vec4(t.x, t.y, t.z, T::one()) ])
+	}
+
+	/// Returns the transform as an affine matrix with three
+	/// rows and four columns (GLSL's `mat4x3`), the last column
+	/// being the translation.
+	pub fn to_mat4x3(&self) -> Mat4x3<T> {
+		let r = self.real.to_mat3();
+
+		Mat4x3::from_cols([ r[0], r[1], r[2], self.translation() ])
+	}
+}
+
+impl<T> DualQuat<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasSqrt + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Blends a set of transforms using dual quaternion linear
+	/// blending. Each transform is scaled by the matching weight,
+	/// the results are summed and the sum is normalized.
+	/// Transforms whose rotation lies in the opposite hemisphere
+	/// of the first one are negated so that the blend takes the
+	/// shortest path.
+	///
+	/// # Panics
+	/// Panics if `dqs` is empty or `weights` has a different
+	/// length to `dqs`.
+	pub fn blend(dqs: &[DualQuat<T>], weights: &[T]) -> Self {
+		assert_eq!(dqs.len(), weights.len(), "Mismatched number of weights in blend");
+		assert!(!dqs.is_empty(), "Cannot blend an empty set of transforms");
+
+		let pivot = dqs[0].real;
+		let zero = Quat::new(T::zero(), T::zero(), T::zero(), T::zero());
+		let mut sum = DualQuat::new(zero, zero);
+
+		for (dq, &w) in dqs.iter().zip(weights) {
+			let w = if dot(pivot, dq.real) < T::zero() { -w } else { w };
+
+			sum.real = sum.real + dq.real * w;
+			sum.dual = sum.dual + dq.dual * w;
+		}
+
+		sum.normalize()
+	}
+}
+
+impl<T> HasNormalize for DualQuat<T>
+	where T: Sized + Copy + HasSqrt + Add<Output = T> + Sub<Output = T>
+		+ Mul<Output = T> + Div<Output = T>
+{
+	/// Scales the dual quaternion so that the real part has
+	/// length 1 and removes the part of the dual quaternion
+	/// that isn't orthogonal to the real part. The result is
+	/// a unit dual quaternion that represents a rigid transform.
+	fn normalize(&self) -> Self {
+		let len = dot(self.real, self.real).sqrt();
+		let real = self.real / len;
+		let dual = self.dual / len;
+
+		Self::new(real, dual - real * dot(real, dual))
+	}
+}
+
+impl<T> Mul for DualQuat<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T> + Sub<Output = T>
+{
+	type Output = Self;
+
+	/// Composes two transforms. The result applies `rhs`
+	/// first and then `self`.
+	fn mul(self, rhs: Self) -> Self {
+		Self::new(
+			self.real * rhs.real,
+			self.real * rhs.dual + self.dual * rhs.real)
+	}
+}
@@ -0,0 +1,95 @@
+
+use vec3::*;
+use mat3::*;
+use traits::*;
+
+use std::ops::*;
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasAbs + HasSqrt + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Returns the eigenvalues and eigenvectors of a symmetric
+	/// matrix. The eigenvalues are sorted in ascending order and
+	/// column `i` of the returned matrix is the unit eigenvector
+	/// for eigenvalue `i`. The eigenvectors are orthonormal.
+	///
+	/// This uses the cyclic Jacobi method, which is accurate even
+	/// for repeated or tiny eigenvalues. Only symmetric matrices
+	/// give meaningful results.
+	pub fn symmetric_eigen(&self) -> (Vec3<T>, Mat3<T>) {
+		const MAX_SWEEPS: usize = 50;
+
+		let zero = T::zero();
+		let hundred = {
+			let ten = (0..10).fold(zero, |acc, _| acc + T::one());
+			ten * ten
+		};
+		let half = T::one() / (T::one() + T::one());
+
+		let mut a = *self;
+		let mut v = Mat3::identity();
+
+		for sweep in 0..MAX_SWEEPS {
+			let off = a[1][0].abs() + a[2][0].abs() + a[2][1].abs();
+			if off == zero {
+				break;
+			}
+
+			for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+				let apq = a[q][p];
+				let g = hundred * apq.abs();
+
+				// Once the off diagonal element is too small to
+				// change the diagonal there is nothing left to do.
+				if sweep > 3 && a[p][p].abs() + g == a[p][p].abs()
+					&& a[q][q].abs() + g == a[q][q].abs()
+				{
+					a[q][p] = zero;
+					a[p][q] = zero;
+					continue;
+				}
+				if apq == zero {
+					continue;
+				}
+
+				let h = a[q][q] - a[p][p];
+				let t = if h.abs() + g == h.abs() {
+					apq / h
+				}
+				else {
+					let theta = half * h / apq;
+					let t = T::one() / (theta.abs() + (T::one() + theta * theta).sqrt());
+
+					if theta < zero { -t } else { t }
+				};
+				let c = T::one() / (T::one() + t * t).sqrt();
+				let s = t * c;
+
+				let mut rot = Mat3::identity();
+				rot[p][p] = c;
+				rot[q][q] = c;
+				rot[q][p] = s;
+				rot[p][q] = -s;
+
+				a = rot.transpose() * a * rot;
+				a[q][p] = zero;
+				a[p][q] = zero;
+				v *= rot;
+			}
+		}
+
+		let mut values = vec3(a[0][0], a[1][1], a[2][2]);
+
+		for &(i, j) in &[(0, 1), (1, 2), (0, 1)] {
+			if values[j] < values[i] {
+				let tmp = values[i];
+				values[i] = values[j];
+				values[j] = tmp;
+				v.cols.swap(i, j);
+			}
+		}
+
+		(values, v)
+	}
+}
@@ -0,0 +1,212 @@
+
+use vec3::*;
+use mat3::*;
+use traits::*;
+use functions::atan2;
+
+use std::ops::*;
+
+/// The axes, in the order they are applied, of the three
+/// rotations that make up a set of Euler angles.
+///
+/// The first six orders use three different axes (Tait-Bryan
+/// angles) while the last six repeat the first axis (proper
+/// Euler angles).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+	XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+	XYX, XZX, YXY, YZY, ZXZ, ZYZ
+}
+
+impl EulerOrder {
+	fn axes(self) -> [usize; 3] {
+		match self {
+			EulerOrder::XYZ => [0, 1, 2],
+			EulerOrder::XZY => [0, 2, 1],
+			EulerOrder::YXZ => [1, 0, 2],
+			EulerOrder::YZX => [1, 2, 0],
+			EulerOrder::ZXY => [2, 0, 1],
+			EulerOrder::ZYX => [2, 1, 0],
+			EulerOrder::XYX => [0, 1, 0],
+			EulerOrder::XZX => [0, 2, 0],
+			EulerOrder::YXY => [1, 0, 1],
+			EulerOrder::YZY => [1, 2, 1],
+			EulerOrder::ZXZ => [2, 0, 2],
+			EulerOrder::ZYZ => [2, 1, 2]
+		}
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + HasTrig + Zero + One + Mul<Output = T>
+	       + Sub<Output = T> + Add<Output = T>,
+		  Vec3<T>: HasNormalize
+{
+	fn from_axis_index(axis: usize, angle: T) -> Self {
+		let mut v = Vec3::zero();
+		v[axis] = T::one();
+
+		Mat3::from_axis_angle(v, angle)
+	}
+
+	/// Creates a rotation from extrinsic Euler angles. The
+	/// rotations are about the fixed axes and are applied in
+	/// the order given, so for `EulerOrder::XYZ` the result is
+	/// `Rz * Ry * Rx`. `angles.x` is the angle for the first
+	/// axis of the order, `angles.y` for the second and
+	/// `angles.z` for the third.
+	pub fn from_euler(order: EulerOrder, angles: Vec3<T>) -> Self {
+		let [a, b, c] = order.axes();
+
+		Self::from_axis_index(c, angles.z)
+			* Self::from_axis_index(b, angles.y)
+			* Self::from_axis_index(a, angles.x)
+	}
+
+	/// Creates a rotation from intrinsic Euler angles. Each
+	/// rotation is about the axes of the frame produced by the
+	/// ones before it, so for `EulerOrder::XYZ` the result is
+	/// `Rx * Ry * Rz`.
+	pub fn from_euler_intrinsic(order: EulerOrder, angles: Vec3<T>) -> Self {
+		let [a, b, c] = order.axes();
+
+		Self::from_axis_index(a, angles.x)
+			* Self::from_axis_index(b, angles.y)
+			* Self::from_axis_index(c, angles.z)
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + HasTrig + HasAtan2 + HasSqrt + Zero + One + PartialOrd
+		+ Neg<Output = T> + Add<Output = T> + Sub<Output = T>
+		+ Mul<Output = T> + Div<Output = T>
+{
+	// Finds the angles such that self == R_a0(x) * R_a1(y) * R_a2(z).
+	//
+	// The matrix is first permuted so that the rotation axes
+	// become X, Y and (Z or X again). If that permutation is odd
+	// the permuted frame is left-handed and all the angles come
+	// out negated. The first angle is found on its own and the
+	// third is then solved for from the matrix with the first
+	// rotation removed. Doing it this way gives a consistent
+	// result in gimbal lock, where the first and third rotation
+	// are about the same axis and only their sum is determined.
+	fn euler_angles(&self, axes: [usize; 3]) -> Vec3<T> {
+		let i = axes[0];
+		let j = axes[1];
+		let k = 3 - i - j;
+		let odd = (i + 1) % 3 != j;
+		let e = |r: usize, c: usize| self[[i, j, k][c]][[i, j, k][r]];
+
+		let angles = if axes[2] == axes[0] {
+			// Both (a, b, c) and (a + pi, -b, c + pi) are solutions
+			// here. Pick the one that keeps the middle angle in
+			// [0, pi] once the angles have been negated.
+			let sb = (e(0, 1) * e(0, 1) + e(0, 2) * e(0, 2)).sqrt();
+			let (a, b) = if odd {
+				(atan2(-e(1, 0), e(2, 0)), -atan2(sb, e(0, 0)))
+			} else {
+				(atan2(e(1, 0), -e(2, 0)), atan2(sb, e(0, 0)))
+			};
+			let (sa, ca) = (a.sin(), a.cos());
+			let c = atan2(-(ca * e(1, 2) + sa * e(2, 2)), ca * e(1, 1) + sa * e(2, 1));
+
+			vec3(a, b, c)
+		}
+		else {
+			let a = atan2(-e(1, 2), e(2, 2));
+			let (sa, ca) = (a.sin(), a.cos());
+			let b = atan2(e(0, 2), (e(1, 2) * e(1, 2) + e(2, 2) * e(2, 2)).sqrt());
+			let c = atan2(ca * e(1, 0) + sa * e(2, 0), ca * e(1, 1) + sa * e(2, 1));
+
+			vec3(a, b, c)
+		};
+
+		if odd { -angles } else { angles }
+	}
+
+	/// Returns the extrinsic Euler angles of a rotation matrix,
+	/// the inverse of `from_euler`. For Tait-Bryan orders the
+	/// middle angle is in [-pi/2, pi/2] and for proper Euler
+	/// orders it is in [0, pi]. In gimbal lock, when the first
+	/// and last axes line up, the third angle is chosen so that
+	/// the result still reproduces the matrix.
+	pub fn to_euler(&self, order: EulerOrder) -> Vec3<T> {
+		let [a, b, c] = order.axes();
+		let angles = self.euler_angles([c, b, a]);
+
+		vec3(angles.z, angles.y, angles.x)
+	}
+
+	/// Returns the intrinsic Euler angles of a rotation matrix,
+	/// the inverse of `from_euler_intrinsic`.
+	pub fn to_euler_intrinsic(&self, order: EulerOrder) -> Vec3<T> {
+		self.euler_angles(order.axes())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::f64::consts::{PI, FRAC_PI_2};
+
+	const ORDERS: [EulerOrder; 12] = [
+		EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ,
+		EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+		EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY,
+		EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ
+	];
+
+	// Middle angles that hit gimbal lock for one kind of
+	// order or the other, along with some ordinary ones.
+	const MIDDLE: [f64; 7] = [0.0, 0.4, -1.1, FRAC_PI_2, -FRAC_PI_2, 2.5, PI];
+
+	fn assert_close(a: Mat3<f64>, b: Mat3<f64>, order: EulerOrder) {
+		for c in 0..3 {
+			for r in 0..3 {
+				assert!((a[c][r] - b[c][r]).abs() < 1e-9,
+					"{:?}: {:?} != {:?}", order, a, b);
+			}
+		}
+	}
+
+	#[test]
+	fn extrinsic_round_trip() {
+		for &order in &ORDERS {
+			for &b in &MIDDLE {
+				let m = Mat3::from_euler(order, vec3(0.3, b, -2.2));
+				let angles = m.to_euler(order);
+
+				assert_close(Mat3::from_euler(order, angles), m, order);
+			}
+		}
+	}
+
+	#[test]
+	fn intrinsic_round_trip() {
+		for &order in &ORDERS {
+			for &b in &MIDDLE {
+				let m = Mat3::from_euler_intrinsic(order, vec3(-1.3, b, 0.7));
+				let angles = m.to_euler_intrinsic(order);
+
+				assert_close(Mat3::from_euler_intrinsic(order, angles), m, order);
+			}
+		}
+	}
+
+	#[test]
+	fn middle_angle_range() {
+		for (i, &order) in ORDERS.iter().enumerate() {
+			for &b in &MIDDLE {
+				let y = Mat3::from_euler(order, vec3(0.3, b, -2.2)).to_euler(order).y;
+
+				if i < 6 {
+					assert!(y.abs() <= FRAC_PI_2 + 1e-9, "{:?}: {}", order, y);
+				}
+				else {
+					assert!((-1e-9..=PI + 1e-9).contains(&y), "{:?}: {}", order, y);
+				}
+			}
+		}
+	}
+}
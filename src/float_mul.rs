@@ -34,3 +34,5 @@ macro_rules! add_mul_all {
 
 add_mul_all!(f32);
 add_mul_all!(f64);
+add_mul_all!(i32);
+add_mul_all!(u32);
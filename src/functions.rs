@@ -96,46 +96,114 @@ pub fn fract<T: HasFract>(x: T) -> T {
 	x.fract()
 }
 
+/// Returns the nearest integer to `x` whose absolute value
+/// is not larger than that of `x`. For vectors this operation
+/// is done component-wise.
+pub fn trunc<T: HasTrunc>(x: T) -> T {
+	x.trunc()
+}
+
+/// Returns the nearest integer to `x`. Values halfway between
+/// two integers are rounded away from zero. For vectors this
+/// operation is done component-wise.
+pub fn round<T: HasRound>(x: T) -> T {
+	x.round()
+}
+
+/// Returns the nearest integer to `x`. Values halfway between
+/// two integers are rounded to the even integer, so 2.5 and
+/// 1.5 both round to 2.0. This is equivalent to `roundEven`
+/// in GLSL. For vectors this operation is done component-wise.
+pub fn round_even<T: HasRound>(x: T) -> T {
+	x.round_even()
+}
+
+/// Splits `x` into its fractional and whole parts and returns
+/// them in that order. Both parts have the same sign as `x`.
+/// GLSL's `modf` returns the fractional part and writes the
+/// whole part to its second argument. For vectors this
+/// operation is done component-wise.
+pub fn modf<T: HasModf>(x: T) -> (T, T) {
+	x.modf()
+}
+
+/// Returns `a * b + c`, computed with a single rounding
+/// where the platform supports it. For vectors this
+/// operation is done component-wise.
+pub fn fma<T: HasFma>(a: T, b: T, c: T) -> T {
+	a.fma(b, c)
+}
+
+/// Splits `x` into a significand in [0.5, 1) and an exponent
+/// such that `x == significand * 2^exponent`. Zero, infinity
+/// and NaN are returned unchanged with an exponent of 0. For
+/// vectors this operation is done component-wise and the
+/// exponents are returned as an integer vector.
+pub fn frexp<T: HasFrexp>(x: T) -> (T, T::Exponent) {
+	x.frexp()
+}
+
+/// Returns `x * 2^exp`, the inverse of
+/// [`frexp`](fn.frexp.html). For vectors this operation
+/// is done component-wise.
+pub fn ldexp<T: HasFrexp>(x: T, exp: T::Exponent) -> T {
+	x.ldexp(exp)
+}
+
 /// Returns `x - y * floor(x / y)`. For vectors this is 
-/// operation is done component-wise. This function is
-/// equivalent to the `mod` function in GLSL.
-///
-/// Note: Implemented using the `%` operator.
-pub fn modulus<T: Rem<Output = T>>(x: T, y: T) -> T {
+/// operation is done component-wise and `y` may also be
+/// a scalar. This function is equivalent to the `mod`
+/// function in GLSL, so the result has the same sign as `y`.
+pub fn modulus<T: HasMod<U>, U>(x: T, y: U) -> T {
+	x.modulus(y)
+}
+
+/// Returns the remainder of `x / y` using the `%` operator,
+/// which truncates the quotient towards zero. Unlike
+/// [`modulus`](fn.modulus.html) the result has the same sign
+/// as `x`.
+pub fn rem<T: Rem<U, Output = T>, U>(x: T, y: U) -> T {
 	x % y
 }
 
 /// Returns the smaller of the two arguments. For vectors
-/// this operation is done component-wise.
-pub fn min<T: HasMinMax>(x: T, y: T) -> T {
+/// this operation is done component-wise and `y` may also
+/// be a scalar.
+pub fn min<T: HasMinMax<U>, U>(x: T, y: U) -> T {
 	x.min(y)
 }
 
 /// Returns the larger of the two arguments. For vectors
-/// this operation is done component-wise.
-pub fn max<T: HasMinMax>(x: T, y: T) -> T {
+/// this operation is done component-wise and `y` may also
+/// be a scalar.
+pub fn max<T: HasMinMax<U>, U>(x: T, y: U) -> T {
 	x.max(y)
 }
 
 /// Returns `x` is `x` is larger than `min`. If `x` 
 /// is smaller than `min`, `min` is returned. If `x`
 /// is larger than `max`, `max` is returned. For vectors
-/// this operation is done component-wise.
-pub fn clamp<T: HasClamp>(x: T, min: T::ElemType, max: T::ElemType) -> T {
+/// this operation is done component-wise and the bounds
+/// may be either vectors or scalars.
+pub fn clamp<T: HasClamp<B>, B>(x: T, min: B, max: B) -> T {
 	x.clamp(min, max)
 }
 
-/// Returns the linear interpolation of `x` and `y`. 
-/// (i.e. `x * f + y * (1 - f)`). For vectors this is 
-/// performed component-wise.
-pub fn mix<T: HasMix>(x: T, y: T, f: T::ElemType) -> T {
-	x.mix(y, f)
+/// Returns the linear interpolation of `x` and `y`
+/// (i.e. `x * (1 - a) + y * a`). For vectors this is 
+/// performed component-wise and `a` may either be a
+/// scalar or a vector of weights.
+///
+/// If `a` is a boolean, or a vector of booleans, then
+/// `y` is selected where `a` is true and `x` otherwise.
+pub fn mix<T: HasMix<A>, A>(x: T, y: T, a: A) -> T {
+	x.mix(y, a)
 }
 
 /// Returns 0.0 if `x` is smaller than `edge` and 1.0
 /// otherwise. For vectors this operation is done 
-/// component-wise.
-pub fn step<T: HasStep>(edge: T, x: T) -> T {
+/// component-wise and `edge` may also be a scalar.
+pub fn step<T: HasStep<E>, E>(edge: E, x: T) -> T {
 	x.step(edge)
 }
 
@@ -143,8 +211,8 @@ pub fn step<T: HasStep>(edge: T, x: T) -> T {
 /// if `x` is larger then `edge1`. Otherwise the return
 /// value is interpolated between 0.0 and 1.0 using 
 /// Hermite polynomials. For vectors this operation is 
-/// done component-wise.
-pub fn smoothstep<T: HasSmoothStep>(edge0: T, edge1: T, x: T) -> T {
+/// done component-wise and the edges may also be scalars.
+pub fn smoothstep<T: HasSmoothStep<E>, E>(edge0: E, edge1: E, x: T) -> T {
 	x.smoothstep(edge0, edge1)
 }
 
@@ -184,6 +252,62 @@ pub fn atan<T: HasTrig>(x: T) -> T {
 	x.atan()
 }
 
+/// Returns the arctangent of `y / x`, using the signs of
+/// both arguments to find the quadrant. The result is in
+/// [-pi, pi]. This is the two argument `atan` in GLSL. For
+/// vectors this operation is done component-wise.
+pub fn atan2<T: HasAtan2>(y: T, x: T) -> T {
+	y.atan2(x)
+}
+
+/// Returns the hyperbolic sine of `x`. For vectors this
+/// operation is done component-wise.
+pub fn sinh<T: HasHyperbolic>(x: T) -> T {
+	x.sinh()
+}
+
+/// Returns the hyperbolic cosine of `x`. For vectors this
+/// operation is done component-wise.
+pub fn cosh<T: HasHyperbolic>(x: T) -> T {
+	x.cosh()
+}
+
+/// Returns the hyperbolic tangent of `x`. For vectors this
+/// operation is done component-wise.
+pub fn tanh<T: HasHyperbolic>(x: T) -> T {
+	x.tanh()
+}
+
+/// Returns the inverse hyperbolic sine of `x`. For vectors
+/// this operation is done component-wise.
+pub fn asinh<T: HasHyperbolic>(x: T) -> T {
+	x.asinh()
+}
+
+/// Returns the inverse hyperbolic cosine of `x`. For vectors
+/// this operation is done component-wise.
+pub fn acosh<T: HasHyperbolic>(x: T) -> T {
+	x.acosh()
+}
+
+/// Returns the inverse hyperbolic tangent of `x`. For vectors
+/// this operation is done component-wise.
+pub fn atanh<T: HasHyperbolic>(x: T) -> T {
+	x.atanh()
+}
+
+/// Converts `degrees` to radians. For vectors this
+/// operation is done component-wise.
+pub fn radians<T: HasAngleConversion>(degrees: T) -> T {
+	degrees.radians()
+}
+
+/// Converts `radians` to degrees. For vectors this
+/// operation is done component-wise.
+pub fn degrees<T: HasAngleConversion>(radians: T) -> T {
+	radians.degrees()
+}
+
 /// Returns `x` raised to the power `y`. For vectors this
 /// operation is done component-wise.
 pub fn pow<T: HasExponential>(x: T, y: T) -> T {
@@ -224,3 +348,66 @@ pub fn sqrt<T: HasSqrt>(x: T) -> T {
 pub fn inversesqrt<T: HasSqrt>(x: T) -> T {
 	x.inv_sqrt()
 }
+
+/// Returns the component-wise comparison `x < y`.
+/// Equivalent to `lessThan` in GLSL.
+pub fn less_than<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.less_than(y)
+}
+
+/// Returns the component-wise comparison `x <= y`.
+/// Equivalent to `lessThanEqual` in GLSL.
+pub fn less_than_equal<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.less_than_equal(y)
+}
+
+/// Returns the component-wise comparison `x > y`.
+/// Equivalent to `greaterThan` in GLSL.
+pub fn greater_than<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.greater_than(y)
+}
+
+/// Returns the component-wise comparison `x >= y`.
+/// Equivalent to `greaterThanEqual` in GLSL.
+pub fn greater_than_equal<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.greater_than_equal(y)
+}
+
+/// Returns the component-wise comparison `x == y`.
+pub fn equal<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.equal(y)
+}
+
+/// Returns the component-wise comparison `x != y`.
+/// Equivalent to `notEqual` in GLSL.
+pub fn not_equal<T: HasRelational>(x: T, y: T) -> T::Output {
+	x.not_equal(y)
+}
+
+/// Returns true if any component of `x` is true.
+pub fn any<T: HasBoolOps>(x: T) -> bool {
+	x.any()
+}
+
+/// Returns true if all components of `x` are true.
+pub fn all<T: HasBoolOps>(x: T) -> bool {
+	x.all()
+}
+
+/// Returns the component-wise logical complement of `x`.
+pub fn not<T: HasBoolOps>(x: T) -> T {
+	x.not()
+}
+
+/// Returns true if `x` is NaN. For vectors this operation is
+/// done component-wise and returns a boolean vector.
+pub fn isnan<T: HasFloatClass>(x: T) -> T::Output {
+	x.is_nan()
+}
+
+/// Returns true if `x` is positive or negative infinity. For
+/// vectors this operation is done component-wise and returns
+/// a boolean vector.
+pub fn isinf<T: HasFloatClass>(x: T) -> T::Output {
+	x.is_inf()
+}
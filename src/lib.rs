@@ -18,7 +18,18 @@ mod trait_impls;
 mod functions;
 mod float_mul;
 
+mod mat2;
 mod mat3;
+mod mat4;
+mod euler;
+mod eigen;
+mod svd;
+mod lu;
+mod nonsquare;
+mod projection;
+mod quat;
+mod dualquat;
+mod transform;
 
 pub mod prelude {
 	//! Traits that are used to provide the implementations
@@ -34,7 +45,16 @@ pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
 pub use functions::*;
+pub use mat2::*;
 pub use mat3::*;
+pub use mat4::*;
+pub use euler::*;
+pub use lu::*;
+pub use nonsquare::*;
+pub use projection::*;
+pub use quat::*;
+pub use dualquat::*;
+pub use transform::*;
 
 mod swizzle;
 pub use swizzle::*;
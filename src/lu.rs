@@ -0,0 +1,156 @@
+
+use vec3::*;
+use mat3::*;
+use traits::*;
+
+use std::ops::*;
+
+/// The LU factorization of a `Mat3`, computed with partial
+/// pivoting by `Mat3::lu`. The factorization can be reused to
+/// solve for several right hand sides.
+#[derive(Copy, Clone, Debug)]
+pub struct Lu3<T: Sized> {
+	// Row i holds row i of both L (below the diagonal, with
+	// an implicit unit diagonal) and U (on and above it).
+	rows: [[T; 3]; 3],
+	// Row i of the factorization is row perm[i] of the matrix.
+	perm: [usize; 3],
+	odd: bool
+}
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + Zero + PartialOrd + HasAbs
+		+ Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Factorizes the matrix as `P * M = L * U` using partial
+	/// pivoting. Returns `None` if the matrix is singular, that
+	/// is if the absolute value of a pivot is not larger than
+	/// `epsilon`.
+	pub fn lu(&self, epsilon: T) -> Option<Lu3<T>> {
+		let mut rows = [
+			self.row(0).as_array(),
+			self.row(1).as_array(),
+			self.row(2).as_array()
+		];
+		let mut perm = [0, 1, 2];
+		let mut odd = false;
+
+		for k in 0..3 {
+			let mut pivot = k;
+			for i in (k + 1)..3 {
+				if rows[i][k].abs() > rows[pivot][k].abs() {
+					pivot = i;
+				}
+			}
+			if rows[pivot][k].abs() <= epsilon {
+				return None;
+			}
+			if pivot != k {
+				rows.swap(pivot, k);
+				perm.swap(pivot, k);
+				odd = !odd;
+			}
+
+			let pivot_row = rows[k];
+			for row in rows.iter_mut().skip(k + 1) {
+				let f = row[k] / pivot_row[k];
+				row[k] = f;
+				for (x, &p) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+					*x = *x - f * p;
+				}
+			}
+		}
+
+		Some(Lu3 { rows, perm, odd })
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + Zero + PartialOrd + HasAbs + Add<Output = T>
+		+ Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Solves `self * x = b` for `x`, returning `None` if the
+	/// matrix is singular to within `epsilon`, see `lu`. This is
+	/// more accurate than multiplying by the inverse. Use `lu` to
+	/// solve for several `b` with the same matrix.
+	///
+	/// The tolerance is required, as for `try_inverse`, because
+	/// rounding means the pivots of a singular matrix are rarely
+	/// exactly zero: `[[1, 2, 3], [4, 5, 6], [7, 8, 9]]` has a last
+	/// pivot of about 1e-16 in `f64`. A good choice is the machine
+	/// epsilon times the size of the largest element.
+	pub fn solve(&self, b: Vec3<T>, epsilon: T) -> Option<Vec3<T>> {
+		self.lu(epsilon).map(|lu| lu.solve(b))
+	}
+}
+
+impl<T> Lu3<T>
+	where T: Sized + Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Solves `M * x = b` for `x`, where `M` is the factorized
+	/// matrix.
+	pub fn solve(&self, b: Vec3<T>) -> Vec3<T> {
+		let a = &self.rows;
+		let mut x = [b[self.perm[0]], b[self.perm[1]], b[self.perm[2]]];
+
+		// Forward substitution with L, then back substitution with U.
+		x[1] = x[1] - a[1][0] * x[0];
+		x[2] = x[2] - a[2][0] * x[0] - a[2][1] * x[1];
+
+		x[2] = x[2] / a[2][2];
+		x[1] = (x[1] - a[1][2] * x[2]) / a[1][1];
+		x[0] = (x[0] - a[0][1] * x[1] - a[0][2] * x[2]) / a[0][0];
+
+		vec3(x[0], x[1], x[2])
+	}
+}
+
+impl<T> Lu3<T>
+	where T: Sized + Copy + Neg<Output = T> + Mul<Output = T>
+{
+	/// Returns the determinant of the factorized matrix.
+	pub fn determinant(&self) -> T {
+		let a = &self.rows;
+		let det = a[0][0] * a[1][1] * a[2][2];
+
+		if self.odd { -det } else { det }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn solve() {
+		let m = Mat3::from_rows([
+			vec3(0.0, 3.0, 0.0),
+			vec3(4.0, 0.5, 2.0),
+			vec3(-2.0, 1.0, 5.0)
+		]);
+		let b = vec3(1.0, -2.0, 3.5);
+		let r = m * m.solve(b, 1e-12).unwrap() - b;
+
+		assert!(r.x.abs() < 1e-12 && r.y.abs() < 1e-12 && r.z.abs() < 1e-12);
+		assert!((m.lu(1e-12).unwrap().determinant() - m.determinant()).abs() < 1e-12);
+	}
+
+	#[test]
+	fn singular() {
+		let m = Mat3::from_rows([
+			vec3(1.0f64, 2.0, 3.0),
+			vec3(4.0, 5.0, 6.0),
+			vec3(7.0, 8.0, 9.0)
+		]);
+		let m32 = Mat3::from_rows([
+			vec3(1.0f32, 2.0, 3.0),
+			vec3(4.0, 5.0, 6.0),
+			vec3(7.0, 8.0, 9.0)
+		]);
+
+		assert!(m.lu(1e-12).is_none());
+		assert!(m.solve(vec3(1.0, 0.0, 0.0), 1e-12).is_none());
+		assert!(m32.lu(1e-6).is_none());
+		assert!(m32.solve(vec3(1.0, 0.0, 0.0), 1e-6).is_none());
+	}
+}
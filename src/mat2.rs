@@ -0,0 +1,114 @@
+
+use vec2::*;
+use traits::*;
+use functions::dot;
+
+use std::ops::*;
+
+/// A 2x2 matrix. The matrix is stored in column-major order,
+/// so `m[i]` is the i-th column, the same as in GLSL.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Mat2<T: Sized> {
+	pub cols: [Vec2<T>; 2]
+}
+
+impl<T: Sized + Clone> Mat2<T> {
+	/// Creates a matrix from its elements in column-major
+	/// order, like the GLSL `mat2` constructor.
+	pub fn from_cols_array(vals: [T; 4]) -> Self {
+		Self { cols: [
+			vec2(vals[0].clone(), vals[1].clone()),
+			vec2(vals[2].clone(), vals[3].clone()) ]
+		}
+	}
+
+	/// Creates a matrix from its columns.
+	pub fn from_cols(cols: [Vec2<T>; 2]) -> Self {
+		Self { cols }
+	}
+	/// Creates a matrix from its rows.
+	pub fn from_rows(rows: [Vec2<T>; 2]) -> Self {
+		let m = Self { cols: rows };
+
+		Self { cols: [ m.row(0), m.row(1) ] }
+	}
+
+	/// Returns row `idx` of the matrix.
+	pub fn row(&self, idx: usize) -> Vec2<T> {
+		vec2(self[0][idx].clone(), self[1][idx].clone())
+	}
+	/// Returns column `idx` of the matrix.
+	pub fn col(&self, idx: usize) -> Vec2<T> {
+		self.cols[idx].clone()
+	}
+
+	/// Returns the elements of the matrix in column-major
+	/// order, which is the layout GLSL expects.
+	pub fn as_array(self) -> [T; 4] {
+		let [c0, c1] = self.cols;
+		let [a, b] = c0.as_array();
+		let [c, d] = c1.as_array();
+
+		[a, b, c, d]
+	}
+}
+
+impl<T> Mat2<T>
+	where T: Sized + Copy + HasTrig + Neg<Output = T>
+{
+	/// Creates a matrix that rotates vectors counter-clockwise
+	/// by `angle` radians.
+	pub fn from_angle(angle: T) -> Mat2<T> {
+		let sin = angle.sin();
+		let cos = angle.cos();
+
+		Mat2::from_cols_array([
+			 cos, sin,
+			-sin, cos
+		])
+	}
+}
+
+impl<T> Mat2<T>
+	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T>
+		+ Neg<Output = T> + One + Div<Output = T>
+{
+	pub fn inverse(&self) -> Self {
+		let invdet = T::one() / (self[0][0] * self[1][1] - self[0][1] * self[1][0]);
+
+		Mat2::from_cols_array([
+			 self[1][1] * invdet, -self[0][1] * invdet,
+			-self[1][0] * invdet,  self[0][0] * invdet
+		])
+	}
+}
+
+impl<T> Index<usize> for Mat2<T>
+	where T: Sized
+{
+	type Output = Vec2<T>;
+
+	fn index(&self, idx: usize) -> &Vec2<T> {
+		&self.cols[idx]
+	}
+}
+
+impl<T> Mul for Mat2<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		Mat2::from_cols([ self * rhs[0], self * rhs[1] ])
+	}
+}
+
+impl<T> Mul<Vec2<T>> for Mat2<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	type Output = Vec2<T>;
+
+	fn mul(self, rhs: Vec2<T>) -> Vec2<T> {
+		vec2(dot(self.row(0), rhs), dot(self.row(1), rhs))
+	}
+}
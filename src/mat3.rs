@@ -5,22 +5,51 @@ use functions::dot;
 
 use std::ops::*;
 
+/// A 3x3 matrix. The matrix is stored in column-major order,
+/// so `m[i]` is the i-th column, the same as in GLSL.
 #[derive(Copy, Clone, Default, Debug)]
 pub struct Mat3<T: Sized> {
-	pub rows: [Vec3<T>; 3]
+	pub cols: [Vec3<T>; 3]
 }
 
 impl<T: Sized + Clone> Mat3<T> {
-	pub fn new(vals: [T; 9]) -> Self {
-		Self { rows: [
+	/// Creates a matrix from its elements in column-major
+	/// order, like the GLSL `mat3` constructor.
+	pub fn from_cols_array(vals: [T; 9]) -> Self {
+		Self { cols: [
 			vec3(vals[0].clone(), vals[1].clone(), vals[2].clone()),
 			vec3(vals[3].clone(), vals[4].clone(), vals[5].clone()),
-			vec3(vals[6].clone(), vals[7].clone(), vals[8].clone()) ]	
+			vec3(vals[6].clone(), vals[7].clone(), vals[8].clone()) ]
 		}
 	}
 
-	pub fn from_vecs(rows: [Vec3<T>; 3]) -> Self {
-		Self { rows	}
+	/// Creates a matrix from its columns.
+	pub fn from_cols(cols: [Vec3<T>; 3]) -> Self {
+		Self { cols }
+	}
+	/// Creates a matrix from its rows.
+	pub fn from_rows(rows: [Vec3<T>; 3]) -> Self {
+		Self { cols: rows }.transpose()
+	}
+
+	/// Returns row `idx` of the matrix.
+	pub fn row(&self, idx: usize) -> Vec3<T> {
+		vec3(self[0][idx].clone(), self[1][idx].clone(), self[2][idx].clone())
+	}
+	/// Returns column `idx` of the matrix.
+	pub fn col(&self, idx: usize) -> Vec3<T> {
+		self.cols[idx].clone()
+	}
+
+	/// Returns the elements of the matrix in column-major
+	/// order, which is the layout GLSL expects.
+	pub fn as_array(self) -> [T; 9] {
+		let [c0, c1, c2] = self.cols;
+		let [a, b, c] = c0.as_array();
+		let [d, e, f] = c1.as_array();
+		let [g, h, i] = c2.as_array();
+
+		[a, b, c, d, e, f, g, h, i]
 	}
 }
 
@@ -44,10 +73,58 @@ impl<T> Mat3<T>
 			u.y * u.z * (T::one() - cos) - u.x * sin);
 		let r3 = vec3(
 			u.z * u.x * (T::one() - cos) - u.y * sin,
-			u.x * u.y * (T::one() - cos) + u.x * sin,
+			u.z * u.y * (T::one() - cos) + u.x * sin,
 			cos + u.z * u.z * (T::one() - cos));
 
-		Mat3::from_vecs([ r1, r2, r3 ])
+		Mat3::from_rows([ r1, r2, r3 ])
+	}
+}
+
+impl<T: Sized + Clone> Mat3<T> {
+	/// Returns the matrix with its rows and columns swapped.
+	pub fn transpose(&self) -> Self {
+		Mat3::from_cols([ self.row(0), self.row(1), self.row(2) ])
+	}
+}
+
+impl<T: Sized + Clone + Zero + One> Mat3<T> {
+	/// Returns the identity matrix.
+	pub fn identity() -> Self {
+		Mat3::from_cols([ Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z() ])
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Sized + Clone + Add<Output = T>
+{
+	/// Returns the sum of the diagonal elements.
+	pub fn trace(&self) -> T {
+		self[0][0].clone() + self[1][1].clone() + self[2][2].clone()
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T> + Add<Output = T>
+{
+	pub fn determinant(&self) -> T {
+		self[0][0] * (self[1][1] * self[2][2] - self[2][1] * self[1][2]) -
+		self[0][1] * (self[1][0] * self[2][2] - self[1][2] * self[2][0]) +
+		self[0][2] * (self[1][0] * self[2][1] - self[1][1] * self[2][0])
+	}
+
+	// The adjugate (transposed cofactor matrix) multiplied by `factor`.
+	fn scaled_adjugate(&self, factor: T) -> Self {
+		Mat3::from_cols_array([
+			(self[1][1] * self[2][2] - self[2][1] * self[1][2]) * factor,
+			(self[0][2] * self[2][1] - self[0][1] * self[2][2]) * factor,
+			(self[0][1] * self[1][2] - self[0][2] * self[1][1]) * factor,
+			(self[1][2] * self[2][0] - self[1][0] * self[2][2]) * factor,
+			(self[0][0] * self[2][2] - self[0][2] * self[2][0]) * factor,
+			(self[1][0] * self[0][2] - self[0][0] * self[1][2]) * factor,
+			(self[1][0] * self[2][1] - self[2][0] * self[1][1]) * factor,
+			(self[2][0] * self[0][1] - self[0][0] * self[2][1]) * factor,
+			(self[0][0] * self[1][1] - self[1][0] * self[0][1]) * factor
+		])
 	}
 }
 
@@ -55,23 +132,38 @@ impl<T> Mat3<T>
 	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T> 
 		+ Add<Output = T> + One + Div<Output = T>
 {
+	/// Returns the inverse of the matrix. The result is not
+	/// finite if the matrix is singular, use `try_inverse`
+	/// when that is possible.
 	pub fn inverse(&self) -> Self {
-		let invdet = T::one() / (
-			self[0][0] * (self[1][1] * self[2][2] - self[2][1] * self[1][2]) -
-			self[0][1] * (self[1][0] * self[2][2] - self[1][2] * self[2][0]) +
-			self[0][2] * (self[1][0] * self[2][1] - self[1][1] * self[2][0]));
-
-		Mat3::new([
-			(self[1][1] * self[2][2] - self[2][1] * self[1][2]) * invdet,
-			(self[0][2] * self[2][1] - self[0][1] * self[2][2]) * invdet,
-			(self[0][1] * self[1][2] - self[0][2] * self[1][1]) * invdet,
-			(self[1][2] * self[2][0] - self[1][0] * self[2][2]) * invdet,
-			(self[0][0] * self[2][2] - self[0][2] * self[2][0]) * invdet,
-			(self[1][0] * self[0][2] - self[0][0] * self[1][2]) * invdet,
-			(self[1][0] * self[2][1] - self[2][0] * self[1][1]) * invdet,
-			(self[2][0] * self[0][1] - self[0][0] * self[2][1]) * invdet,
-			(self[0][0] * self[1][1] - self[1][0] * self[0][1]) * invdet
-		])
+		self.inverse_and_determinant().0
+	}
+
+	/// Returns the inverse of the matrix along with its
+	/// determinant, so that callers can decide how to treat
+	/// matrices that are close to singular.
+	pub fn inverse_and_determinant(&self) -> (Self, T) {
+		let det = self.determinant();
+
+		(self.scaled_adjugate(T::one() / det), det)
+	}
+}
+
+impl<T> Mat3<T>
+	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T> + Add<Output = T>
+		+ One + Div<Output = T> + HasAbs + PartialOrd
+{
+	/// Returns the inverse of the matrix, or `None` if the
+	/// absolute value of the determinant is not larger
+	/// than `epsilon`.
+	pub fn try_inverse(&self, epsilon: T) -> Option<Self> {
+		let det = self.determinant();
+
+		if det.abs() <= epsilon {
+			return None;
+		}
+
+		Some(self.scaled_adjugate(T::one() / det))
 	}
 }
 
@@ -81,7 +173,130 @@ impl<T> Index<usize> for Mat3<T>
 	type Output = Vec3<T>;
 
 	fn index(&self, idx: usize) -> &Vec3<T> {
-		&self.rows[idx]
+		&self.cols[idx]
+	}
+}
+
+impl<T> IndexMut<usize> for Mat3<T>
+	where T: Sized
+{
+	fn index_mut(&mut self, idx: usize) -> &mut Vec3<T> {
+		&mut self.cols[idx]
+	}
+}
+
+impl<T> PartialEq for Mat3<T>
+	where T: Sized + Clone + PartialEq
+{
+	fn eq(&self, rhs: &Self) -> bool {
+		(0..3).all(|i| (0..3).all(|j| self[i][j] == rhs[i][j]))
+	}
+}
+
+impl<T> Add for Mat3<T>
+	where T: Add<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		let [a0, a1, a2] = self.cols;
+		let [b0, b1, b2] = rhs.cols;
+
+		Mat3::from_cols([ a0 + b0, a1 + b1, a2 + b2 ])
+	}
+}
+impl<T> Sub for Mat3<T>
+	where T: Sub<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		let [a0, a1, a2] = self.cols;
+		let [b0, b1, b2] = rhs.cols;
+
+		Mat3::from_cols([ a0 - b0, a1 - b1, a2 - b2 ])
+	}
+}
+impl<T> Neg for Mat3<T>
+	where T: Neg<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn neg(self) -> Self {
+		let [c0, c1, c2] = self.cols;
+
+		Mat3::from_cols([ -c0, -c1, -c2 ])
+	}
+}
+
+impl<T> Mul<T> for Mat3<T>
+	where T: Mul<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn mul(self, rhs: T) -> Self {
+		let [c0, c1, c2] = self.cols;
+
+		Mat3::from_cols([ c0 * rhs.clone(), c1 * rhs.clone(), c2 * rhs ])
+	}
+}
+impl<T> Div<T> for Mat3<T>
+	where T: Div<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn div(self, rhs: T) -> Self {
+		let [c0, c1, c2] = self.cols;
+
+		Mat3::from_cols([ c0 / rhs.clone(), c1 / rhs.clone(), c2 / rhs ])
+	}
+}
+
+impl<T> AddAssign for Mat3<T>
+	where T: AddAssign + Sized + Clone
+{
+	fn add_assign(&mut self, rhs: Self) {
+		let [b0, b1, b2] = rhs.cols;
+
+		self.cols[0] += b0;
+		self.cols[1] += b1;
+		self.cols[2] += b2;
+	}
+}
+impl<T> SubAssign for Mat3<T>
+	where T: SubAssign + Sized + Clone
+{
+	fn sub_assign(&mut self, rhs: Self) {
+		let [b0, b1, b2] = rhs.cols;
+
+		self.cols[0] -= b0;
+		self.cols[1] -= b1;
+		self.cols[2] -= b2;
+	}
+}
+impl<T> MulAssign for Mat3<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+impl<T> MulAssign<T> for Mat3<T>
+	where T: MulAssign + Sized + Clone
+{
+	fn mul_assign(&mut self, rhs: T) {
+		self.cols[0] *= rhs.clone();
+		self.cols[1] *= rhs.clone();
+		self.cols[2] *= rhs;
+	}
+}
+impl<T> DivAssign<T> for Mat3<T>
+	where T: DivAssign + Sized + Clone
+{
+	fn div_assign(&mut self, rhs: T) {
+		self.cols[0] /= rhs.clone();
+		self.cols[1] /= rhs.clone();
+		self.cols[2] /= rhs;
 	}
 }
 
@@ -91,16 +306,7 @@ impl<T> Mul for Mat3<T>
 	type Output = Self;
 
 	fn mul(self, rhs: Self) -> Self {
-		let cols = [
-			vec3(rhs[0][0], rhs[1][0], rhs[2][0]),
-			vec3(rhs[0][1], rhs[1][1], rhs[2][1]),
-			vec3(rhs[0][2], rhs[1][2], rhs[2][2]) ];
-
-		return Mat3::new([
-			dot(self[0], cols[0]), dot(self[0], cols[1]), dot(self[0], cols[2]),
-			dot(self[1], cols[0]), dot(self[1], cols[1]), dot(self[1], cols[2]),
-			dot(self[2], cols[0]), dot(self[2], cols[1]), dot(self[2], cols[2]) 
-		]);
+		Mat3::from_cols([ self * rhs[0], self * rhs[1], self * rhs[2] ])
 	}
 }
 
@@ -110,6 +316,6 @@ impl<T> Mul<Vec3<T>> for Mat3<T>
 	type Output = Vec3<T>;
 
 	fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
-		vec3(dot(self[0], rhs), dot(self[1], rhs),dot(self[2], rhs))
+		vec3(dot(self.row(0), rhs), dot(self.row(1), rhs), dot(self.row(2), rhs))
 	}
 }
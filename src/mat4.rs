@@ -0,0 +1,216 @@
+
+use vec3::*;
+use vec4::*;
+use mat3::*;
+use traits::*;
+use functions::dot;
+
+use std::ops::*;
+
+/// A 4x4 matrix. The matrix is stored in column-major order,
+/// so `m[i]` is the i-th column, the same as in GLSL.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Mat4<T: Sized> {
+	pub cols: [Vec4<T>; 4]
+}
+
+impl<T: Sized + Clone> Mat4<T> {
+	/// Creates a matrix from its elements in column-major
+	/// order, like the GLSL `mat4` constructor.
+	pub fn from_cols_array(vals: [T; 16]) -> Self {
+		Self { cols: [
+			vec4(vals[0].clone(),  vals[1].clone(),  vals[2].clone(),  vals[3].clone()),
+			vec4(vals[4].clone(),  vals[5].clone(),  vals[6].clone(),  vals[7].clone()),
+			vec4(vals[8].clone(),  vals[9].clone(),  vals[10].clone(), vals[11].clone()),
+			vec4(vals[12].clone(), vals[13].clone(), vals[14].clone(), vals[15].clone()) ]
+		}
+	}
+
+	/// Creates a matrix from its columns.
+	pub fn from_cols(cols: [Vec4<T>; 4]) -> Self {
+		Self { cols }
+	}
+	/// Creates a matrix from its rows.
+	pub fn from_rows(rows: [Vec4<T>; 4]) -> Self {
+		let m = Self { cols: rows };
+
+		Self { cols: [ m.row(0), m.row(1), m.row(2), m.row(3) ] }
+	}
+
+	/// Returns row `idx` of the matrix.
+	pub fn row(&self, idx: usize) -> Vec4<T> {
+		vec4(
+			self[0][idx].clone(),
+			self[1][idx].clone(),
+			self[2][idx].clone(),
+			self[3][idx].clone())
+	}
+	/// Returns column `idx` of the matrix.
+	pub fn col(&self, idx: usize) -> Vec4<T> {
+		self.cols[idx].clone()
+	}
+
+	/// Returns the elements of the matrix in column-major
+	/// order, which is the layout GLSL expects.
+	pub fn as_array(self) -> [T; 16] {
+		let [c0, c1, c2, c3] = self.cols;
+		let [a, b, c, d] = c0.as_array();
+		let [e, f, g, h] = c1.as_array();
+		let [i, j, k, l] = c2.as_array();
+		let [m, n, o, p] = c3.as_array();
+
+		[a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p]
+	}
+}
+
+impl<T: Sized + Copy + Zero + One> Mat4<T> {
+	/// Embeds a 3x3 matrix in the upper left corner of
+	/// an otherwise identity matrix.
+	pub fn from_mat3(m: Mat3<T>) -> Self {
+		let (o, l) = (T::zero(), T::one());
+
+		Mat4::from_cols_array([
+			m[0][0], m[0][1], m[0][2], o,
+			m[1][0], m[1][1], m[1][2], o,
+			m[2][0], m[2][1], m[2][2], o,
+			o,       o,       o,       l
+		])
+	}
+
+	/// Creates a matrix that translates points by `offset`.
+	pub fn from_translation(offset: Vec3<T>) -> Self {
+		let (o, l) = (T::zero(), T::one());
+
+		Mat4::from_cols_array([
+			l,        o,        o,        o,
+			o,        l,        o,        o,
+			o,        o,        l,        o,
+			offset.x, offset.y, offset.z, l
+		])
+	}
+
+	/// Creates a matrix that scales each axis by the
+	/// corresponding component of `scale`.
+	pub fn from_scale(scale: Vec3<T>) -> Self {
+		let (o, l) = (T::zero(), T::one());
+
+		Mat4::from_cols_array([
+			scale.x, o,       o,       o,
+			o,       scale.y, o,       o,
+			o,       o,       scale.z, o,
+			o,       o,       o,       l
+		])
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Sized + Copy + HasTrig + Zero + One + Mul<Output = T>
+	       + Sub<Output = T> + Add<Output = T>,
+		  Vec3<T>: HasNormalize
+{
+	/// Creates a matrix that rotates by `angle` radians around `axis`.
+	/// This is the rotation from `Mat3::from_axis_angle`.
+	pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Mat4<T> {
+		Mat4::from_mat3(Mat3::from_axis_angle(axis, angle))
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T> + Add<Output = T>
+{
+	// The 2x2 determinants of the first two and last two
+	// columns that the cofactor expansion is built from.
+	fn sub_factors(&self) -> ([T; 6], [T; 6]) {
+		let m = self;
+
+		let s = [
+			m[0][0] * m[1][1] - m[1][0] * m[0][1],
+			m[0][0] * m[1][2] - m[1][0] * m[0][2],
+			m[0][0] * m[1][3] - m[1][0] * m[0][3],
+			m[0][1] * m[1][2] - m[1][1] * m[0][2],
+			m[0][1] * m[1][3] - m[1][1] * m[0][3],
+			m[0][2] * m[1][3] - m[1][2] * m[0][3] ];
+		let c = [
+			m[2][0] * m[3][1] - m[3][0] * m[2][1],
+			m[2][0] * m[3][2] - m[3][0] * m[2][2],
+			m[2][0] * m[3][3] - m[3][0] * m[2][3],
+			m[2][1] * m[3][2] - m[3][1] * m[2][2],
+			m[2][1] * m[3][3] - m[3][1] * m[2][3],
+			m[2][2] * m[3][3] - m[3][2] * m[2][3] ];
+
+		(s, c)
+	}
+
+	pub fn determinant(&self) -> T {
+		let (s, c) = self.sub_factors();
+
+		s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Copy + Sized + Mul<Output = T> + Sub<Output = T>
+		+ Add<Output = T> + One + Div<Output = T>
+{
+	pub fn inverse(&self) -> Self {
+		let m = self;
+		let (s, c) = self.sub_factors();
+		let invdet = T::one() / self.determinant();
+
+		Mat4::from_cols_array([
+			(m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3]) * invdet,
+			(m[0][2] * c[4] - m[0][1] * c[5] - m[0][3] * c[3]) * invdet,
+			(m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3]) * invdet,
+			(m[2][2] * s[4] - m[2][1] * s[5] - m[2][3] * s[3]) * invdet,
+
+			(m[1][2] * c[2] - m[1][0] * c[5] - m[1][3] * c[1]) * invdet,
+			(m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1]) * invdet,
+			(m[3][2] * s[2] - m[3][0] * s[5] - m[3][3] * s[1]) * invdet,
+			(m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]) * invdet,
+
+			(m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0]) * invdet,
+			(m[0][1] * c[2] - m[0][0] * c[4] - m[0][3] * c[0]) * invdet,
+			(m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0]) * invdet,
+			(m[2][1] * s[2] - m[2][0] * s[4] - m[2][3] * s[0]) * invdet,
+
+			(m[1][1] * c[1] - m[1][0] * c[3] - m[1][2] * c[0]) * invdet,
+			(m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0]) * invdet,
+			(m[3][1] * s[1] - m[3][0] * s[3] - m[3][2] * s[0]) * invdet,
+			(m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]) * invdet
+		])
+	}
+}
+
+impl<T> Index<usize> for Mat4<T>
+	where T: Sized
+{
+	type Output = Vec4<T>;
+
+	fn index(&self, idx: usize) -> &Vec4<T> {
+		&self.cols[idx]
+	}
+}
+
+impl<T> Mul for Mat4<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		Mat4::from_cols([ self * rhs[0], self * rhs[1], self * rhs[2], self * rhs[3] ])
+	}
+}
+
+impl<T> Mul<Vec4<T>> for Mat4<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	type Output = Vec4<T>;
+
+	fn mul(self, rhs: Vec4<T>) -> Vec4<T> {
+		vec4(
+			dot(self.row(0), rhs),
+			dot(self.row(1), rhs),
+			dot(self.row(2), rhs),
+			dot(self.row(3), rhs))
+	}
+}
@@ -0,0 +1,144 @@
+//! The non-square matrix types. These follow the GLSL
+//! naming convention where `MatCxR` has `C` columns and
+//! `R` rows, so a `Mat3x4` multiplies a `Vec3` to give a
+//! `Vec4`.
+
+use vec2::*;
+use vec3::*;
+use vec4::*;
+use mat2::*;
+use mat3::*;
+use mat4::*;
+use functions::dot;
+
+use std::ops::*;
+
+macro_rules! nonsquare_matrix {
+	(
+		$name:ident, $transpose:ident, $colvec:ident, $rowvec:ident,
+		$ncols:expr, $nrows:expr, $len:expr, [$($c:expr),*], [$($r:expr),*],
+		[$([$($i:expr),*]),*]
+	) => {
+		/// A non-square matrix. The matrix is stored in column-major
+		/// order, so `m[i]` is the i-th column, the same as in GLSL.
+		#[derive(Copy, Clone, Default, Debug)]
+		pub struct $name<T: Sized> {
+			pub cols: [$colvec<T>; $ncols]
+		}
+
+		impl<T: Sized + Clone> $name<T> {
+			/// Creates a matrix from its elements in column-major
+			/// order, like the GLSL constructor.
+			pub fn from_cols_array(vals: [T; $len]) -> Self {
+				Self { cols: [ $( $colvec::new([ $( vals[$i].clone() ),* ]) ),* ] }
+			}
+
+			/// Creates a matrix from its columns.
+			pub fn from_cols(cols: [$colvec<T>; $ncols]) -> Self {
+				Self { cols }
+			}
+			/// Creates a matrix from its rows.
+			pub fn from_rows(rows: [$rowvec<T>; $nrows]) -> Self {
+				$transpose::from_cols(rows).transpose()
+			}
+
+			/// Returns row `idx` of the matrix.
+			pub fn row(&self, idx: usize) -> $rowvec<T> {
+				$rowvec::new([ $( self[$c][idx].clone() ),* ])
+			}
+			/// Returns column `idx` of the matrix.
+			pub fn col(&self, idx: usize) -> $colvec<T> {
+				self.cols[idx].clone()
+			}
+
+			/// Returns the transpose of the matrix, which has
+			/// the number of rows and columns swapped.
+			pub fn transpose(&self) -> $transpose<T> {
+				$transpose::from_cols([ $( self.row($r) ),* ])
+			}
+
+			/// Returns the elements of the matrix in column-major
+			/// order, which is the layout GLSL expects.
+			pub fn as_array(self) -> [T; $len] {
+				[ $( $( self.cols[$i / $nrows][$i % $nrows].clone() ),* ),* ]
+			}
+		}
+
+		impl<T> Index<usize> for $name<T>
+			where T: Sized
+		{
+			type Output = $colvec<T>;
+
+			fn index(&self, idx: usize) -> &$colvec<T> {
+				&self.cols[idx]
+			}
+		}
+
+		impl<T> Mul<$rowvec<T>> for $name<T>
+			where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+		{
+			type Output = $colvec<T>;
+
+			fn mul(self, rhs: $rowvec<T>) -> $colvec<T> {
+				$colvec::new([ $( dot(self.row($r), rhs) ),* ])
+			}
+		}
+	}
+}
+
+nonsquare_matrix!(Mat2x3, Mat3x2, Vec3, Vec2, 2, 3, 6,  [0, 1],       [0, 1, 2],
+	[[0, 1, 2], [3, 4, 5]]);
+nonsquare_matrix!(Mat2x4, Mat4x2, Vec4, Vec2, 2, 4, 8,  [0, 1],       [0, 1, 2, 3],
+	[[0, 1, 2, 3], [4, 5, 6, 7]]);
+nonsquare_matrix!(Mat3x2, Mat2x3, Vec2, Vec3, 3, 2, 6,  [0, 1, 2],    [0, 1],
+	[[0, 1], [2, 3], [4, 5]]);
+nonsquare_matrix!(Mat3x4, Mat4x3, Vec4, Vec3, 3, 4, 12, [0, 1, 2],    [0, 1, 2, 3],
+	[[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]);
+nonsquare_matrix!(Mat4x2, Mat2x4, Vec2, Vec4, 4, 2, 8,  [0, 1, 2, 3], [0, 1],
+	[[0, 1], [2, 3], [4, 5], [6, 7]]);
+nonsquare_matrix!(Mat4x3, Mat3x4, Vec3, Vec4, 4, 3, 12, [0, 1, 2, 3], [0, 1, 2],
+	[[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]]);
+
+// Each column of the product is the left hand side
+// multiplied by the matching column of the right hand side.
+macro_rules! matrix_product {
+	($lhs:ident * $rhs:ident = $out:ident, [$($c:expr),*]) => {
+		impl<T> Mul<$rhs<T>> for $lhs<T>
+			where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+		{
+			type Output = $out<T>;
+
+			fn mul(self, rhs: $rhs<T>) -> $out<T> {
+				$out::from_cols([ $( self * rhs[$c] ),* ])
+			}
+		}
+	}
+}
+
+matrix_product!(Mat2   * Mat3x2 = Mat3x2, [0, 1, 2]);
+matrix_product!(Mat2   * Mat4x2 = Mat4x2, [0, 1, 2, 3]);
+matrix_product!(Mat3   * Mat2x3 = Mat2x3, [0, 1]);
+matrix_product!(Mat3   * Mat4x3 = Mat4x3, [0, 1, 2, 3]);
+matrix_product!(Mat4   * Mat2x4 = Mat2x4, [0, 1]);
+matrix_product!(Mat4   * Mat3x4 = Mat3x4, [0, 1, 2]);
+
+matrix_product!(Mat2x3 * Mat2   = Mat2x3, [0, 1]);
+matrix_product!(Mat2x3 * Mat3x2 = Mat3,   [0, 1, 2]);
+matrix_product!(Mat2x3 * Mat4x2 = Mat4x3, [0, 1, 2, 3]);
+matrix_product!(Mat2x4 * Mat2   = Mat2x4, [0, 1]);
+matrix_product!(Mat2x4 * Mat3x2 = Mat3x4, [0, 1, 2]);
+matrix_product!(Mat2x4 * Mat4x2 = Mat4,   [0, 1, 2, 3]);
+
+matrix_product!(Mat3x2 * Mat2x3 = Mat2,   [0, 1]);
+matrix_product!(Mat3x2 * Mat3   = Mat3x2, [0, 1, 2]);
+matrix_product!(Mat3x2 * Mat4x3 = Mat4x2, [0, 1, 2, 3]);
+matrix_product!(Mat3x4 * Mat2x3 = Mat2x4, [0, 1]);
+matrix_product!(Mat3x4 * Mat3   = Mat3x4, [0, 1, 2]);
+matrix_product!(Mat3x4 * Mat4x3 = Mat4,   [0, 1, 2, 3]);
+
+matrix_product!(Mat4x2 * Mat2x4 = Mat2,   [0, 1]);
+matrix_product!(Mat4x2 * Mat3x4 = Mat3x2, [0, 1, 2]);
+matrix_product!(Mat4x2 * Mat4   = Mat4x2, [0, 1, 2, 3]);
+matrix_product!(Mat4x3 * Mat2x4 = Mat2x3, [0, 1]);
+matrix_product!(Mat4x3 * Mat3x4 = Mat3,   [0, 1, 2]);
+matrix_product!(Mat4x3 * Mat4   = Mat4x3, [0, 1, 2, 3]);
@@ -0,0 +1,316 @@
+//! Projection and view matrix constructors for `Mat4`.
+//!
+//! Every projection comes in four variants. `rh` and `lh` pick
+//! a right-handed view space, looking down -Z, or a left-handed
+//! one, looking down +Z. `no` maps the depth range to the
+//! OpenGL clip depth of [-1, 1] while `zo` maps it to the
+//! [0, 1] range used by Vulkan and Direct3D. The unsuffixed
+//! constructors use the OpenGL convention (`rh_no`).
+
+use vec3::*;
+use vec4::*;
+use mat4::*;
+use traits::*;
+use functions::{dot, cross, normalize};
+
+use std::ops::*;
+
+impl<T> Mat4<T>
+	where T: Sized + Copy + Zero + One + HasTrig + Add<Output = T>
+		+ Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T>
+{
+	/// Creates a perspective projection for a right-handed
+	/// view space and the OpenGL depth range. `fovy` is the
+	/// vertical field of view in radians.
+	pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Self {
+		Self::perspective_rh_no(fovy, aspect, near, far)
+	}
+
+	pub fn perspective_rh_no(fovy: T, aspect: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, -(far + near) / (far - near), -(l + l) * far * near / (far - near)),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn perspective_rh_zo(fovy: T, aspect: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, far / (near - far), -far * near / (far - near)),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn perspective_lh_no(fovy: T, aspect: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, (far + near) / (far - near), -(l + l) * far * near / (far - near)),
+			vec4(o, o, l, o)
+		])
+	}
+	pub fn perspective_lh_zo(fovy: T, aspect: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, far / (far - near), -far * near / (far - near)),
+			vec4(o, o, l, o)
+		])
+	}
+
+	/// Creates a perspective projection with the far plane at
+	/// infinity that maps the near plane to a depth of 1 and
+	/// infinity to the far end of the depth range. Reversing the
+	/// depth like this only improves precision with the [0, 1]
+	/// depth range, so this uses the `rh_zo` convention.
+	pub fn perspective_infinite_reversed_z(fovy: T, aspect: T, near: T) -> Self {
+		Self::perspective_infinite_reversed_z_rh_zo(fovy, aspect, near)
+	}
+
+	pub fn perspective_infinite_reversed_z_rh_no(fovy: T, aspect: T, near: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, l, (l + l) * near),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn perspective_infinite_reversed_z_rh_zo(fovy: T, aspect: T, near: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, o, near),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn perspective_infinite_reversed_z_lh_no(fovy: T, aspect: T, near: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, -l, (l + l) * near),
+			vec4(o, o, l, o)
+		])
+	}
+	pub fn perspective_infinite_reversed_z_lh_zo(fovy: T, aspect: T, near: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = l / (fovy / (l + l)).tan();
+
+		Mat4::from_rows([
+			vec4(f / aspect, o, o, o),
+			vec4(o, f, o, o),
+			vec4(o, o, o, near),
+			vec4(o, o, l, o)
+		])
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Sized + Copy + Zero + One + Add<Output = T> + Sub<Output = T>
+		+ Mul<Output = T> + Div<Output = T> + Neg<Output = T>
+{
+	/// Creates an orthographic projection for a right-handed
+	/// view space and the OpenGL depth range.
+	pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		Self::orthographic_rh_no(left, right, bottom, top, near, far)
+	}
+
+	pub fn orthographic_rh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two / (right - left), o, o, -(right + left) / (right - left)),
+			vec4(o, two / (top - bottom), o, -(top + bottom) / (top - bottom)),
+			vec4(o, o, -two / (far - near), -(far + near) / (far - near)),
+			vec4(o, o, o, l)
+		])
+	}
+	pub fn orthographic_rh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two / (right - left), o, o, -(right + left) / (right - left)),
+			vec4(o, two / (top - bottom), o, -(top + bottom) / (top - bottom)),
+			vec4(o, o, -l / (far - near), -near / (far - near)),
+			vec4(o, o, o, l)
+		])
+	}
+	pub fn orthographic_lh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two / (right - left), o, o, -(right + left) / (right - left)),
+			vec4(o, two / (top - bottom), o, -(top + bottom) / (top - bottom)),
+			vec4(o, o, two / (far - near), -(far + near) / (far - near)),
+			vec4(o, o, o, l)
+		])
+	}
+	pub fn orthographic_lh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two / (right - left), o, o, -(right + left) / (right - left)),
+			vec4(o, two / (top - bottom), o, -(top + bottom) / (top - bottom)),
+			vec4(o, o, l / (far - near), -near / (far - near)),
+			vec4(o, o, o, l)
+		])
+	}
+
+	/// Creates a perspective projection from the bounds of the
+	/// near plane, for a right-handed view space and the OpenGL
+	/// depth range. This is the matrix built by `glFrustum`.
+	pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		Self::frustum_rh_no(left, right, bottom, top, near, far)
+	}
+
+	pub fn frustum_rh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two * near / (right - left), o, (right + left) / (right - left), o),
+			vec4(o, two * near / (top - bottom), (top + bottom) / (top - bottom), o),
+			vec4(o, o, -(far + near) / (far - near), -two * far * near / (far - near)),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn frustum_rh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two * near / (right - left), o, (right + left) / (right - left), o),
+			vec4(o, two * near / (top - bottom), (top + bottom) / (top - bottom), o),
+			vec4(o, o, far / (near - far), -far * near / (far - near)),
+			vec4(o, o, -l, o)
+		])
+	}
+	pub fn frustum_lh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two * near / (right - left), o, -(right + left) / (right - left), o),
+			vec4(o, two * near / (top - bottom), -(top + bottom) / (top - bottom), o),
+			vec4(o, o, (far + near) / (far - near), -two * far * near / (far - near)),
+			vec4(o, o, l, o)
+		])
+	}
+	pub fn frustum_lh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let two = l + l;
+
+		Mat4::from_rows([
+			vec4(two * near / (right - left), o, -(right + left) / (right - left), o),
+			vec4(o, two * near / (top - bottom), -(top + bottom) / (top - bottom), o),
+			vec4(o, o, far / (far - near), -far * near / (far - near)),
+			vec4(o, o, l, o)
+		])
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Sized + Copy + Zero + One + Add<Output = T> + Sub<Output = T>
+		+ Mul<Output = T> + Neg<Output = T>,
+		  Vec3<T>: HasNormalize
+{
+	/// Creates a right-handed view matrix for a camera at `eye`
+	/// looking towards `center`.
+	pub fn look_at(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Self {
+		Self::look_at_rh(eye, center, up)
+	}
+
+	/// Creates a view matrix for a camera at `eye` looking
+	/// towards `center` that looks down -Z in view space.
+	pub fn look_at_rh(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = normalize(center - eye);
+		let s = normalize(cross(f, up));
+		let u = cross(s, f);
+
+		Mat4::from_rows([
+			vec4( s.x,  s.y,  s.z, -dot(s, eye)),
+			vec4( u.x,  u.y,  u.z, -dot(u, eye)),
+			vec4(-f.x, -f.y, -f.z,  dot(f, eye)),
+			vec4(o, o, o, l)
+		])
+	}
+	/// Creates a view matrix for a camera at `eye` looking
+	/// towards `center` that looks down +Z in view space.
+	pub fn look_at_lh(eye: Vec3<T>, center: Vec3<T>, up: Vec3<T>) -> Self {
+		let (o, l) = (T::zero(), T::one());
+		let f = normalize(center - eye);
+		let s = normalize(cross(up, f));
+		let u = cross(f, s);
+
+		Mat4::from_rows([
+			vec4(s.x, s.y, s.z, -dot(s, eye)),
+			vec4(u.x, u.y, u.z, -dot(u, eye)),
+			vec4(f.x, f.y, f.z, -dot(f, eye)),
+			vec4(o, o, o, l)
+		])
+	}
+}
+
+/// Converts a normalized device depth in [-1, 1] produced by a
+/// `no` perspective projection back to the distance from the
+/// eye in view space. A value read from an OpenGL depth buffer
+/// has to be remapped with `depth * 2 - 1` first.
+pub fn linearize_depth_no<T>(depth: T, near: T, far: T) -> T
+	where T: Copy + One + Add<Output = T> + Sub<Output = T>
+		+ Mul<Output = T> + Div<Output = T>
+{
+	(T::one() + T::one()) * far * near / (far + near - depth * (far - near))
+}
+
+/// Converts a normalized device depth in [0, 1] produced by a
+/// `zo` perspective projection back to the distance from the
+/// eye in view space.
+pub fn linearize_depth_zo<T>(depth: T, near: T, far: T) -> T
+	where T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	far * near / (far - depth * (far - near))
+}
+
+/// Converts a normalized device depth in [-1, 1] produced by an
+/// infinite reversed-Z `no` projection back to the distance
+/// from the eye in view space.
+pub fn linearize_depth_reversed_infinite_no<T>(depth: T, near: T) -> T
+	where T: Copy + One + Add<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	(T::one() + T::one()) * near / (depth + T::one())
+}
+
+/// Converts a normalized device depth in [0, 1] produced by an
+/// infinite reversed-Z `zo` projection back to the distance
+/// from the eye in view space.
+pub fn linearize_depth_reversed_infinite_zo<T>(depth: T, near: T) -> T
+	where T: Div<Output = T>
+{
+	near / depth
+}
@@ -0,0 +1,280 @@
+
+use vec3::*;
+use mat3::*;
+use traits::*;
+use functions::{dot, cross, normalize};
+
+use std::ops::*;
+
+/// A quaternion `w + x*i + y*j + z*k`. Unit quaternions
+/// represent rotations in 3D.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Quat<T: Sized> {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+	pub w: T
+}
+
+impl<T: Sized + Clone> Quat<T> {
+	/// Creates a quaternion from its components.
+	pub fn new(x: T, y: T, z: T, w: T) -> Self {
+		Self { x, y, z, w }
+	}
+
+	/// Creates a quaternion from its vector and scalar parts.
+	pub fn from_parts(v: Vec3<T>, w: T) -> Self {
+		Self { x: v.x, y: v.y, z: v.z, w }
+	}
+
+	/// Returns the vector part of the quaternion.
+	pub fn vector(&self) -> Vec3<T> {
+		vec3(self.x.clone(), self.y.clone(), self.z.clone())
+	}
+}
+
+impl<T: Sized + Clone + Zero + One> Quat<T> {
+	/// Returns the quaternion representing no rotation.
+	pub fn identity() -> Self {
+		Self::new(T::zero(), T::zero(), T::zero(), T::one())
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + HasTrig + One + Mul<Output = T> + Div<Output = T>
+		+ Add<Output = T>,
+		  Vec3<T>: HasNormalize
+{
+	/// Creates a quaternion that rotates by `angle` radians
+	/// around `axis`. This is the same rotation as
+	/// `Mat3::from_axis_angle`.
+	pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+		let half = angle / (T::one() + T::one());
+
+		Quat::from_parts(axis.normalize() * half.sin(), half.cos())
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Clone + Neg<Output = T>
+{
+	/// Returns the quaternion with the vector part negated.
+	/// For unit quaternions this is the inverse rotation.
+	pub fn conjugate(&self) -> Self {
+		Quat::new(-self.x.clone(), -self.y.clone(), -self.z.clone(), self.w.clone())
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + Neg<Output = T> + Mul<Output = T>
+		+ Add<Output = T> + Div<Output = T>
+{
+	/// Returns the multiplicative inverse of the quaternion.
+	pub fn inverse(&self) -> Self {
+		self.conjugate() / dot(*self, *self)
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+		  Quat<T>: HasNormalize
+{
+	/// Normalized linear interpolation between two rotations.
+	/// This takes the shorter path between them, but does not
+	/// rotate at constant speed.
+	pub fn nlerp(&self, rhs: Self, t: T) -> Self {
+		let rhs = if dot(*self, rhs) < T::zero() { -rhs } else { rhs };
+
+		normalize(*self * (T::one() - t) + rhs * t)
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasTrig + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+		  Quat<T>: HasNormalize
+{
+	/// Spherical linear interpolation between two rotations.
+	/// This takes the shorter path between them at constant
+	/// angular speed.
+	pub fn slerp(&self, rhs: Self, t: T) -> Self {
+		let mut cos = dot(*self, rhs);
+		let mut rhs = rhs;
+
+		if cos < T::zero() {
+			cos = -cos;
+			rhs = -rhs;
+		}
+		if cos >= T::one() {
+			return self.nlerp(rhs, t);
+		}
+
+		let theta = cos.acos();
+		let sin = theta.sin();
+
+		*self * (((T::one() - t) * theta).sin() / sin) + rhs * ((t * theta).sin() / sin)
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+	/// Returns the rotation matrix for a unit quaternion.
+	pub fn to_mat3(&self) -> Mat3<T> {
+		let Quat { x, y, z, w } = *self;
+		let (l, two) = (T::one(), T::one() + T::one());
+
+		Mat3::from_rows([
+			vec3(l - two * (y * y + z * z), two * (x * y - z * w), two * (x * z + y * w)),
+			vec3(two * (x * y + z * w), l - two * (x * x + z * z), two * (y * z - x * w)),
+			vec3(two * (x * z - y * w), two * (y * z + x * w), l - two * (x * x + y * y))
+		])
+	}
+}
+
+impl<T> Quat<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasSqrt + Add<Output = T>
+		+ Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Returns the unit quaternion for a rotation matrix.
+	pub fn from_mat3(m: Mat3<T>) -> Self {
+		let e = |r: usize, c: usize| m[c][r];
+		let l = T::one();
+		let two = l + l;
+		let quarter = l / (two + two);
+		let trace = e(0, 0) + e(1, 1) + e(2, 2);
+
+		// Pick the largest of the four components to divide by
+		// so that the result stays accurate for all rotations.
+		if trace > T::zero() {
+			let s = (trace + l).sqrt() * two;
+			Quat::new(
+				(e(2, 1) - e(1, 2)) / s,
+				(e(0, 2) - e(2, 0)) / s,
+				(e(1, 0) - e(0, 1)) / s,
+				s * quarter)
+		}
+		else if e(0, 0) > e(1, 1) && e(0, 0) > e(2, 2) {
+			let s = (l + e(0, 0) - e(1, 1) - e(2, 2)).sqrt() * two;
+			Quat::new(
+				s * quarter,
+				(e(0, 1) + e(1, 0)) / s,
+				(e(0, 2) + e(2, 0)) / s,
+				(e(2, 1) - e(1, 2)) / s)
+		}
+		else if e(1, 1) > e(2, 2) {
+			let s = (l + e(1, 1) - e(0, 0) - e(2, 2)).sqrt() * two;
+			Quat::new(
+				(e(0, 1) + e(1, 0)) / s,
+				s * quarter,
+				(e(1, 2) + e(2, 1)) / s,
+				(e(0, 2) - e(2, 0)) / s)
+		}
+		else {
+			let s = (l + e(2, 2) - e(0, 0) - e(1, 1)).sqrt() * two;
+			Quat::new(
+				(e(0, 2) + e(2, 0)) / s,
+				(e(1, 2) + e(2, 1)) / s,
+				s * quarter,
+				(e(1, 0) - e(0, 1)) / s)
+		}
+	}
+}
+
+impl<T> HasDot for Quat<T>
+	where T: Mul<Output = T> + Add<Output = T> + Sized + Clone
+{
+	type Output = T;
+
+	fn dot(&self, rhs: Self) -> T {
+		self.x.clone() * rhs.x + self.y.clone() * rhs.y
+			+ self.z.clone() * rhs.z + self.w.clone() * rhs.w
+	}
+}
+
+impl<T> Add for Quat<T>
+	where T: Add<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Quat::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
+	}
+}
+impl<T> Sub for Quat<T>
+	where T: Sub<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Quat::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z, self.w - rhs.w)
+	}
+}
+impl<T> Neg for Quat<T>
+	where T: Neg<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn neg(self) -> Self {
+		Quat::new(-self.x, -self.y, -self.z, -self.w)
+	}
+}
+
+impl<T> Mul<T> for Quat<T>
+	where T: Mul<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn mul(self, rhs: T) -> Self {
+		Quat::new(
+			self.x * rhs.clone(),
+			self.y * rhs.clone(),
+			self.z * rhs.clone(),
+			self.w * rhs)
+	}
+}
+impl<T> Div<T> for Quat<T>
+	where T: Div<Output = T> + Sized + Clone
+{
+	type Output = Self;
+
+	fn div(self, rhs: T) -> Self {
+		Quat::new(
+			self.x / rhs.clone(),
+			self.y / rhs.clone(),
+			self.z / rhs.clone(),
+			self.w / rhs)
+	}
+}
+
+impl<T> Mul for Quat<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T> + Sub<Output = T>
+{
+	type Output = Self;
+
+	/// Composes two rotations. The result applies `rhs`
+	/// first and then `self`.
+	fn mul(self, rhs: Self) -> Self {
+		Quat::new(
+			self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+			self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+			self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+			self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z)
+	}
+}
+
+impl<T> Mul<Vec3<T>> for Quat<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T> + Sub<Output = T>
+{
+	type Output = Vec3<T>;
+
+	/// Rotates a vector by a unit quaternion.
+	fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+		let u = self.vector();
+		let t = cross(u, rhs);
+		let t = t + t;
+
+		rhs + t * self.w + cross(u, t)
+	}
+}
@@ -0,0 +1,88 @@
+
+use vec3::*;
+use mat3::*;
+use traits::*;
+use functions::{dot, cross};
+
+use std::ops::*;
+
+impl<T> Mat3<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasAbs + HasSqrt + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Returns the singular value decomposition `(u, sigma, v)`
+	/// of the matrix, so that `self == u * diag(sigma) * v^T`.
+	///
+	/// Both `u` and `v` are rotations, with determinant +1. To
+	/// allow for this the singular values are sorted in
+	/// descending order of magnitude and the last one is
+	/// negative when the matrix contains a reflection.
+	pub fn svd(&self) -> (Mat3<T>, Vec3<T>, Mat3<T>) {
+		let zero = T::zero();
+		let len = |v: Vec3<T>| dot(v, v).sqrt();
+
+		// The right singular vectors are the eigenvectors of
+		// M^T * M, largest eigenvalue first. Reversing the order
+		// of the columns flips the sign of the determinant.
+		let (_, e) = (self.transpose() * *self).symmetric_eigen();
+		let v2 = if e.determinant() > zero { -e[0] } else { e[0] };
+		let v = Mat3::from_cols([ e[2], e[1], v2 ]);
+
+		let m0 = *self * v[0];
+		let s0 = len(m0);
+		let u0 = if s0 == zero { Vec3::unit_x() } else { m0 / s0 };
+
+		// Remove any error in the direction of u0 so that the
+		// left singular vectors stay orthogonal.
+		let m1 = *self * v[1];
+		let m1 = m1 - u0 * dot(u0, m1);
+		let s1 = len(m1);
+		let u1 = if s1 == zero { perpendicular(u0) } else { m1 / s1 };
+
+		let u2 = cross(u0, u1);
+		let s2 = dot(u2, *self * v[2]);
+
+		(Mat3::from_cols([ u0, u1, u2 ]), vec3(s0, s1, s2), v)
+	}
+
+	/// Returns the polar decomposition `(rotation, stretch)` of
+	/// the matrix, so that `self == rotation * stretch`.
+	///
+	/// `rotation` is the closest rotation to the matrix and has
+	/// determinant +1, so this can be used to orthonormalize a
+	/// rotation matrix that has drifted. `stretch` is symmetric.
+	/// It is only positive semi-definite when the determinant of
+	/// the matrix is not negative.
+	pub fn polar(&self) -> (Mat3<T>, Mat3<T>) {
+		let (u, sigma, v) = self.svd();
+		let zero = T::zero();
+		let s = Mat3::from_cols([
+			vec3(sigma.x, zero, zero),
+			vec3(zero, sigma.y, zero),
+			vec3(zero, zero, sigma.z)
+		]);
+		let vt = v.transpose();
+
+		(u * vt, v * s * vt)
+	}
+}
+
+// Returns a unit vector perpendicular to the unit vector `v`.
+pub fn perpendicular<T>(v: Vec3<T>) -> Vec3<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasAbs + HasSqrt
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	let (x, y, z) = (v.x.abs(), v.y.abs(), v.z.abs());
+	let axis = if x <= y && x <= z {
+		Vec3::unit_x()
+	}
+	else if y <= z {
+		Vec3::unit_y()
+	}
+	else {
+		Vec3::unit_z()
+	};
+	let p = cross(v, axis);
+
+	p / dot(p, p).sqrt()
+}
@@ -1,5 +1,8 @@
 
 use traits::*;
+use vec2::*;
+use vec3::*;
+use vec4::*;
 use functions::clamp;
 
 use std::ops::*;
@@ -48,6 +51,40 @@ macro_rules! implement_functions {
 				(*self).atan()
 			}
 		}
+		impl HasAtan2 for $type {
+			fn atan2(&self, x: Self) -> Self {
+				(*self).atan2(x)
+			}
+		}
+		impl HasHyperbolic for $type {
+			fn sinh(&self) -> Self {
+				(*self).sinh()
+			}
+			fn cosh(&self) -> Self {
+				(*self).cosh()
+			}
+			fn tanh(&self) -> Self {
+				(*self).tanh()
+			}
+
+			fn asinh(&self) -> Self {
+				(*self).asinh()
+			}
+			fn acosh(&self) -> Self {
+				(*self).acosh()
+			}
+			fn atanh(&self) -> Self {
+				(*self).atanh()
+			}
+		}
+		impl HasAngleConversion for $type {
+			fn radians(&self) -> Self {
+				(*self).to_radians()
+			}
+			fn degrees(&self) -> Self {
+				(*self).to_degrees()
+			}
+		}
 		impl HasExponential for $type {
 			fn pow(&self, exp: Self) -> Self {
 				self.powf(exp)
@@ -87,6 +124,34 @@ macro_rules! implement_functions {
 				(*self).fract()
 			}
 		}
+		impl HasTrunc for $type {
+			fn trunc(&self) -> Self {
+				(*self).trunc()
+			}
+		}
+		impl HasRound for $type {
+			fn round(&self) -> Self {
+				(*self).round()
+			}
+			fn round_even(&self) -> Self {
+				(*self).round_ties_even()
+			}
+		}
+		impl HasModf for $type {
+			fn modf(&self) -> (Self, Self) {
+				let whole = (*self).trunc();
+				// Infinity has no fractional part, and taking the
+				// sign of x keeps it on zero results such as -3.0.
+				let fract = if self.is_infinite() { 0.0 } else { *self - whole };
+
+				(fract.copysign(*self), whole)
+			}
+		}
+		impl HasMod for $type {
+			fn modulus(&self, rhs: Self) -> Self {
+				*self - rhs * (*self / rhs).floor()
+			}
+		}
 		impl HasMinMax for $type {
 			fn min(&self, rhs: Self) -> Self {
 				(*self).min(rhs)
@@ -96,10 +161,28 @@ macro_rules! implement_functions {
 			}
 		}
 		impl HasMix for $type {
-			type ElemType = Self;
-		
-			fn mix(&self, rhs: Self, f: Self) -> Self {
-				*self * f + rhs * (1.0 - f)
+			fn mix(&self, rhs: Self, a: Self) -> Self {
+				*self * (1.0 - a) + rhs * a
+			}
+		}
+		impl HasMix<bool> for $type {
+			fn mix(&self, rhs: Self, a: bool) -> Self {
+				if a { rhs } else { *self }
+			}
+		}
+		impl HasMix<$type> for Vec2<$type> {
+			fn mix(&self, rhs: Self, a: $type) -> Self {
+				self.mix(rhs, Vec2::new([a; 2]))
+			}
+		}
+		impl HasMix<$type> for Vec3<$type> {
+			fn mix(&self, rhs: Self, a: $type) -> Self {
+				self.mix(rhs, Vec3::new([a; 3]))
+			}
+		}
+		impl HasMix<$type> for Vec4<$type> {
+			fn mix(&self, rhs: Self, a: $type) -> Self {
+				self.mix(rhs, Vec4::new([a; 4]))
 			}
 		}
 		impl HasStep for $type {
@@ -115,6 +198,22 @@ macro_rules! implement_functions {
 			}
 		}
 
+		impl HasFloatClass for $type {
+			type Output = bool;
+
+			fn is_nan(&self) -> bool {
+				(*self).is_nan()
+			}
+			fn is_inf(&self) -> bool {
+				(*self).is_infinite()
+			}
+		}
+		impl HasFma for $type {
+			fn fma(&self, b: Self, c: Self) -> Self {
+				(*self).mul_add(b, c)
+			}
+		}
+
 		impl Zero for $type {
 			fn zero() -> Self {
 				0.0
@@ -127,8 +226,6 @@ macro_rules! implement_functions {
 		}
 		
 		impl HasClamp for $type {
-			type ElemType = Self;
-
 			fn clamp(&self, min: Self, max: Self) -> Self {
 				self.min(max).max(min)
 			}
@@ -139,6 +236,138 @@ macro_rules! implement_functions {
 implement_functions!(f32);
 implement_functions!(f64);
 
+// $bits is the unsigned integer type with the same size as
+// $type, $mant is the number of explicit bits in the significand
+// and $bias is the exponent bias, which is also the largest
+// exponent of a normal number.
+macro_rules! implement_frexp {
+	($type:ty, $bits:ty, $mant:expr, $bias:expr) => {
+		impl HasFrexp for $type {
+			type Exponent = i32;
+
+			fn frexp(&self) -> (Self, i32) {
+				let bits = self.to_bits();
+				let mask: $bits = (1 << (8 * ::std::mem::size_of::<$bits>() - $mant - 1)) - 1;
+				let exp = ((bits >> $mant) & mask) as i32;
+
+				if exp == 0 {
+					if *self == 0.0 {
+						return (*self, 0);
+					}
+
+					// Subnormal, scale it up to a normal number first.
+					let (m, e) = (*self * (1.0 as $type).ldexp($mant + 1)).frexp();
+					return (m, e - ($mant + 1));
+				}
+				if exp == mask as i32 {
+					// Infinity and NaN are returned unchanged.
+					return (*self, 0);
+				}
+
+				let m = (bits & !(mask << $mant)) | (($bias - 1) << $mant);
+
+				(<$type>::from_bits(m), exp - ($bias - 1))
+			}
+
+			fn ldexp(&self, exp: i32) -> Self {
+				let pow2 = |e: i32| <$type>::from_bits((($bias + e) as $bits) << $mant);
+				let min = 1 - $bias;
+				let mut x = *self;
+				let mut exp = exp;
+
+				// Apply the exponent in steps that are each
+				// representable, so that results near the ends
+				// of the range don't overflow or underflow early.
+				for _ in 0..2 {
+					if exp > $bias {
+						x *= pow2($bias);
+						exp -= $bias;
+					}
+					else if exp < min {
+						x *= pow2(min) * pow2($mant + 1);
+						exp -= min + $mant + 1;
+					}
+				}
+
+				x * pow2(exp.max(min).min($bias))
+			}
+		}
+	}
+}
+
+implement_frexp!(f32, u32, 23, 127);
+implement_frexp!(f64, u64, 52, 1023);
+
+macro_rules! implement_int_functions {
+	($type:ty) => {
+		impl HasMinMax for $type {
+			fn min(&self, rhs: Self) -> Self {
+				::std::cmp::min(*self, rhs)
+			}
+			fn max(&self, rhs: Self) -> Self {
+				::std::cmp::max(*self, rhs)
+			}
+		}
+
+		impl Zero for $type {
+			fn zero() -> Self {
+				0
+			}
+		}
+		impl One for $type {
+			fn one() -> Self {
+				1
+			}
+		}
+
+		impl HasClamp for $type {
+			fn clamp(&self, min: Self, max: Self) -> Self {
+				::std::cmp::max(::std::cmp::min(*self, max), min)
+			}
+		}
+	}
+}
+
+macro_rules! implement_signed_functions {
+	($type:ty) => {
+		impl HasAbs for $type {
+			fn abs(&self) -> Self {
+				// GLSL leaves abs of the most negative value
+				// undefined, wrap instead of panicking.
+				self.wrapping_abs()
+			}
+		}
+		impl HasSign for $type {
+			fn sign(&self) -> Self {
+				self.signum()
+			}
+		}
+		impl HasMod for $type {
+			fn modulus(&self, rhs: Self) -> Self {
+				let r = *self % rhs;
+
+				// Move the result to have the same sign as the divisor.
+				if r != 0 && (r < 0) != (rhs < 0) { r + rhs } else { r }
+			}
+		}
+	}
+}
+
+macro_rules! implement_unsigned_functions {
+	($type:ty) => {
+		impl HasMod for $type {
+			fn modulus(&self, rhs: Self) -> Self {
+				*self % rhs
+			}
+		}
+	}
+}
+
+implement_int_functions!(i32);
+implement_int_functions!(u32);
+implement_signed_functions!(i32);
+implement_unsigned_functions!(u32);
+
 impl<T: HasPerElementOps> HasAbs for T 
 	where T::ElemType: HasAbs
 {
@@ -171,6 +400,49 @@ impl<T: HasPerElementOps> HasTrig for T
 	}
 }
 
+impl<T: HasPerElementBinOps> HasAtan2 for T
+	where T::ElemType: HasAtan2
+{
+	fn atan2(&self, x: Self) -> Self {
+		self.apply_bin_op(x, |y, x| y.atan2(x))
+	}
+}
+
+impl<T: HasPerElementOps> HasHyperbolic for T
+	where T::ElemType: HasHyperbolic
+{
+	fn sinh(&self) -> Self {
+		self.apply_op(|x| x.sinh())
+	}
+	fn cosh(&self) -> Self {
+		self.apply_op(|x| x.cosh())
+	}
+	fn tanh(&self) -> Self {
+		self.apply_op(|x| x.tanh())
+	}
+
+	fn asinh(&self) -> Self {
+		self.apply_op(|x| x.asinh())
+	}
+	fn acosh(&self) -> Self {
+		self.apply_op(|x| x.acosh())
+	}
+	fn atanh(&self) -> Self {
+		self.apply_op(|x| x.atanh())
+	}
+}
+
+impl<T: HasPerElementOps> HasAngleConversion for T
+	where T::ElemType: HasAngleConversion
+{
+	fn radians(&self) -> Self {
+		self.apply_op(|x| x.radians())
+	}
+	fn degrees(&self) -> Self {
+		self.apply_op(|x| x.degrees())
+	}
+}
+
 impl<T: HasPerElementOps> HasSqrt for T
 	where T::ElemType: HasSqrt
 {
@@ -196,6 +468,13 @@ impl<T: HasPerElementOps> HasFloor for T
 		self.apply_op(|ref x| x.floor())
 	}
 }
+impl<T: HasPerElementOps> HasCeil for T 
+	where T::ElemType: HasCeil
+{
+	fn ceil(&self) -> Self {
+		self.apply_op(|x| x.ceil())
+	}
+}
 impl<T: HasPerElementOps> HasFract for T 
 	where T::ElemType: HasFract
 {
@@ -204,6 +483,23 @@ impl<T: HasPerElementOps> HasFract for T
 	}
 }
 
+impl<T: HasPerElementOps> HasTrunc for T 
+	where T::ElemType: HasTrunc
+{
+	fn trunc(&self) -> Self {
+		self.apply_op(|x| x.trunc())
+	}
+}
+impl<T: HasPerElementOps> HasRound for T 
+	where T::ElemType: HasRound
+{
+	fn round(&self) -> Self {
+		self.apply_op(|x| x.round())
+	}
+	fn round_even(&self) -> Self {
+		self.apply_op(|x| x.round_even())
+	}
+}
 impl<T: HasPerElementBinOps> HasMinMax for T 
 	where T::ElemType: HasMinMax
 {
@@ -214,6 +510,13 @@ impl<T: HasPerElementBinOps> HasMinMax for T
 		self.apply_bin_op(rhs, |ref a, b| a.max(b))
 	}
 }
+impl<T: HasPerElementBinOps> HasMod for T
+	where T::ElemType: HasMod
+{
+	fn modulus(&self, rhs: Self) -> Self {
+		self.apply_bin_op(rhs, |a, b| a.modulus(b))
+	}
+}
 impl<T: HasPerElementBinOps> HasStep for T 
 	where T::ElemType: HasStep
 {
@@ -250,6 +553,49 @@ impl<T> HasNormalize for T
 	}
 }
 
+impl<T, E> HasFaceForward for T
+	where T: HasDot<Output = E> + Neg<Output = T> + Clone,
+		  E: Zero + PartialOrd
+{
+	fn faceforward(&self, incident: Self, nref: Self) -> Self {
+		if nref.dot(incident) < E::zero() {
+			self.clone()
+		}
+		else {
+			-self.clone()
+		}
+	}
+}
+impl<T, E> HasReflect for T
+	where T: HasDot<Output = E> + Sub<Output = T> + Mul<E, Output = T> + Clone,
+		  E: Add<Output = E> + Clone
+{
+	fn reflect(&self, normal: Self) -> Self {
+		let d = normal.dot(self.clone());
+
+		self.clone() - normal * (d.clone() + d)
+	}
+}
+impl<T, E> HasRefract for T
+	where T: HasDot<Output = E> + Zero + Sub<Output = T> + Mul<E, Output = T> + Clone,
+		  E: Zero + One + PartialOrd + HasSqrt + Add<Output = E>
+			+ Sub<Output = E> + Mul<Output = E> + Clone
+{
+	type ElemType = E;
+
+	/// Returns zero if there is total internal reflection.
+	fn refract(&self, normal: Self, eta: E) -> Self {
+		let d = normal.dot(self.clone());
+		let k = E::one() - eta.clone() * eta.clone() * (E::one() - d.clone() * d.clone());
+
+		if k < E::zero() {
+			return T::zero();
+		}
+
+		self.clone() * eta.clone() - normal * (eta * d + k.sqrt())
+	}
+}
+
 impl<T: HasPerElementOps + HasPerElementBinOps> HasExponential for T 
 	where <T as HasPerElementOps>::ElemType: HasExponential,
 	      <T as HasPerElementBinOps>::ElemType: HasExponential
@@ -271,13 +617,133 @@ impl<T: HasPerElementOps + HasPerElementBinOps> HasExponential for T
 	}
 }
 
-impl<T: HasPerElementBinOps> HasMix for T
-	where T::ElemType: HasMix<ElemType = T::ElemType> + Clone
-{
-	type ElemType = T::ElemType;
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use functions::*;
+
+	// Every per-component function that the scalar types implement
+	// should also be available for the vector types, with either a
+	// vector or a scalar as the other arguments. This fails to
+	// compile if one of them is missing.
+	#[test]
+	fn per_component_functions() {
+		fn float<T, E>()
+			where T: HasAbs + HasSign + HasFloor + HasCeil + HasFract + HasTrunc
+				+ HasRound + HasModf + HasSqrt
+				+ HasFloatClass + HasFrexp + HasFma + HasTrig + HasAtan2 + HasHyperbolic + HasAngleConversion
+				+ HasExponential + HasMod + HasMod<E> + HasMinMax
+				+ HasMinMax<E> + HasClamp + HasClamp<E> + HasMix + HasMix<E>
+				+ HasStep + HasStep<E> + HasSmoothStep + HasSmoothStep<E>
+		{ }
+		fn int<T, E>()
+			where T: HasMod + HasMod<E> + HasMinMax + HasMinMax<E> + HasClamp + HasClamp<E>
+		{ }
+		fn signed<T>()
+			where T: HasAbs + HasSign
+		{ }
+
+		float::<f32, f32>();
+		float::<Vec2<f32>, f32>();
+		float::<Vec3<f32>, f32>();
+		float::<Vec4<f32>, f32>();
+		float::<f64, f64>();
+		float::<Vec2<f64>, f64>();
+		float::<Vec3<f64>, f64>();
+		float::<Vec4<f64>, f64>();
 
-	fn mix(&self, b: Self, f: Self::ElemType) -> T {
-		self.apply_bin_op(b, move |ref a, b| a.mix(b, f.clone()))
+		int::<i32, i32>();
+		int::<Vec2<i32>, i32>();
+		int::<Vec3<i32>, i32>();
+		int::<Vec4<i32>, i32>();
+		int::<u32, u32>();
+		int::<Vec2<u32>, u32>();
+		int::<Vec3<u32>, u32>();
+		int::<Vec4<u32>, u32>();
+
+		signed::<i32>();
+		signed::<Vec2<i32>>();
+		signed::<Vec3<i32>>();
+		signed::<Vec4<i32>>();
+	}
+
+
+	#[test]
+	fn modf_signs() {
+		let (f, w) = modf(-3.25f64);
+		assert!(f == -0.25 && w == -3.0);
+
+		let (f, w) = modf(-3.0f64);
+		assert!(f == 0.0 && f.is_sign_negative() && w == -3.0);
+
+		let (f, w) = modf(f32::INFINITY);
+		assert!(f == 0.0 && f.is_sign_positive() && w == f32::INFINITY);
+
+		let (f, w) = modf(f64::NEG_INFINITY);
+		assert!(f == 0.0 && f.is_sign_negative() && w == f64::NEG_INFINITY);
+
+		let (f, w) = modf(vec3(5.75f32, -0.25, f32::INFINITY));
+		assert!(f.x == 0.75 && f.y == -0.25 && f.z == 0.0);
+		assert!(w.x == 5.0 && w.y == 0.0 && w.z == f32::INFINITY);
+	}
+	#[test]
+	fn frexp_special_values() {
+		assert_eq!(frexp(1e-45f32), (0.5, -148));
+		assert_eq!(frexp(f32::MAX), (1.0 - f32::EPSILON / 2.0, 128));
+		assert_eq!(frexp(5e-324f64), (0.5, -1073));
+		assert_eq!(frexp(-3.0f64), (-0.75, 2));
+		assert_eq!(frexp(0.0f32), (0.0, 0));
+		assert_eq!(frexp(f64::INFINITY), (f64::INFINITY, 0));
+		assert!(frexp(f32::NAN).0.is_nan());
+
+		let (m, e) = frexp(vec3(8.0f32, -0.75, 1e-45));
+		assert!(all(equal(m, vec3(0.5, -0.75, 0.5))));
+		assert!(all(equal(e, vec3(4, 0, -148))));
 	}
-}
 
+	#[test]
+	fn ldexp_range() {
+		assert_eq!(ldexp(1.0f32, -149), 1e-45);
+		assert_eq!(ldexp(1.0f64, -1074), 5e-324);
+		assert_eq!(ldexp(0.5f64, 1024), 2f64.powi(1023));
+		assert_eq!(ldexp(f64::MAX, -2000), f64::MAX * 2f64.powi(-1000) * 2f64.powi(-1000));
+
+		// Results below the smallest subnormal round to even.
+		assert_eq!(ldexp(1.0f32, -150), 0.0);
+		assert_eq!(ldexp(1.5f32, -149), f32::from_bits(2));
+		assert_eq!(ldexp(2.5f32, -149), f32::from_bits(2));
+		assert_eq!(ldexp(3.0f32, -151), f32::from_bits(1));
+
+		assert_eq!(ldexp(1.0f32, i32::MAX), f32::INFINITY);
+		assert_eq!(ldexp(-1.0f64, i32::MAX), f64::NEG_INFINITY);
+		assert_eq!(ldexp(f32::MAX, i32::MIN), 0.0);
+		assert_eq!(ldexp(1e-310f64, i32::MIN), 0.0);
+		assert_eq!(ldexp(0.0f64, i32::MAX), 0.0);
+		assert_eq!(ldexp(1e-40f32, 200), 1e-40 * 2f32.powi(100) * 2f32.powi(100));
+	}
+
+	#[test]
+	fn frexp_ldexp_round_trip() {
+		let values64 = [
+			1.0, 0.5, -3.0, 123.456, -1e300, 1e-310, 5e-324,
+			f64::MAX, f64::MIN_POSITIVE, 0.0, -0.0, f64::INFINITY
+		];
+		let values32 = [
+			1.0f32, 0.75, -7.5, 1e-40, 1e-45, f32::MAX,
+			f32::MIN_POSITIVE, 0.0, f32::NEG_INFINITY
+		];
+
+		for &x in &values64 {
+			let (m, e) = frexp(x);
+			assert_eq!(ldexp(m, e), x);
+		}
+		for &x in &values32 {
+			let (m, e) = frexp(x);
+			assert_eq!(ldexp(m, e), x);
+		}
+
+		let v = vec4(3.0f64, -1e-310, 1e300, 0.1);
+		let (m, e) = frexp(v);
+		assert!(all(equal(ldexp(m, e), v)));
+	}
+}
@@ -116,6 +116,41 @@ pub trait HasTrig {
 	fn atan(&self) -> Self;
 }
 
+/// Exposes the two argument arctangent for use by the
+/// [`atan2`](../fn.atan2.html) function.
+pub trait HasAtan2 {
+	/// Returns the component-wise arctangent of `self / x`,
+	/// using the signs of both to find the quadrant.
+	fn atan2(&self, x: Self) -> Self;
+}
+
+/// A trait that exposes the hyperbolic functions.
+pub trait HasHyperbolic {
+	/// Returns the component-wise hyperbolic sine of a vector.
+	fn sinh(&self) -> Self;
+	/// Returns the component-wise hyperbolic cosine of a vector.
+	fn cosh(&self) -> Self;
+	/// Returns the component-wise hyperbolic tangent of a vector.
+	fn tanh(&self) -> Self;
+
+	/// Returns the component-wise inverse hyperbolic sine of a vector.
+	fn asinh(&self) -> Self;
+	/// Returns the component-wise inverse hyperbolic cosine of a vector.
+	fn acosh(&self) -> Self;
+	/// Returns the component-wise inverse hyperbolic tangent of a vector.
+	fn atanh(&self) -> Self;
+}
+
+/// Exposes conversions between degrees and radians for use
+/// by the [`radians`](../fn.radians.html) and
+/// [`degrees`](../fn.degrees.html) functions.
+pub trait HasAngleConversion {
+	/// Converts each component from degrees to radians.
+	fn radians(&self) -> Self;
+	/// Converts each component from radians to degrees.
+	fn degrees(&self) -> Self;
+}
+
 /// A trait that exposes exponential functions.
 pub trait HasExponential {
 	/// Returns `x^exponent` for each component in a vector.
@@ -164,47 +199,144 @@ pub trait HasFract {
 	fn fract(&self) -> Self;
 }
 
-/// Exposes min and max operations for vectors.
-pub trait HasMinMax {
+/// Exposes the trunc operation for use by the
+/// [`trunc`](../fn.trunc.html) function.
+pub trait HasTrunc {
+	/// Rounds each component of a vector towards zero.
+	fn trunc(&self) -> Self;
+}
+/// Exposes the rounding operations for use by the
+/// [`round`](../fn.round.html) and
+/// [`round_even`](../fn.round_even.html) functions.
+pub trait HasRound {
+	/// Rounds each component of a vector to the nearest
+	/// integer, with halfway cases rounded away from zero.
+	fn round(&self) -> Self;
+	/// Rounds each component of a vector to the nearest
+	/// integer, with halfway cases rounded to the even one.
+	fn round_even(&self) -> Self;
+}
+/// Exposes the modf operation for use by the
+/// [`modf`](../fn.modf.html) function.
+pub trait HasModf: Sized {
+	/// Returns the fractional and whole parts of each
+	/// component of a vector, both with the sign of the
+	/// component.
+	fn modf(&self) -> (Self, Self);
+}
+
+/// Exposes the floored modulus operation for use by the
+/// [`modulus`](../fn.modulus.html) function.
+pub trait HasMod<Rhs = Self> {
+	/// Returns `self - rhs * floor(self / rhs)` for each component.
+	fn modulus(&self, rhs: Rhs) -> Self;
+}
+
+/// Exposes min and max operations for vectors. `Rhs`
+/// may also be the element type for vectors.
+pub trait HasMinMax<Rhs = Self> {
 	/// Returns the component-wise minimum between two vectors.
-	fn min(&self, rhs: Self) -> Self;
+	fn min(&self, rhs: Rhs) -> Self;
 	/// Returns the component-wise maximum between two vectors.
-	fn max(&self, rhs: Self) -> Self;
+	fn max(&self, rhs: Rhs) -> Self;
 }
 
-/// Exposes a clamp operation for vectors.
-pub trait HasClamp {
-	type ElemType;
-
-	fn clamp(&self, min: Self::ElemType, max: Self::ElemType) -> Self;
+/// Exposes a clamp operation for vectors. The bounds
+/// `B` may be either vectors or scalars.
+pub trait HasClamp<B = Self> {
+	fn clamp(&self, min: B, max: B) -> Self;
 }
 
 /// Exposes the mix operation (linear interpolation)
 /// for use by the [`mix`](../fn.mix.html) function.
-pub trait HasMix {
-	/// The type of the interpolation parameter.
-	type ElemType;
-
+/// `A` is the type of the interpolation parameter.
+pub trait HasMix<A = Self> {
 	/// Returns the linear interpolation between two
-	/// vectors as determined by the `f` parameter.
-	fn mix(&self, rhs: Self, f: Self::ElemType) -> Self;
+	/// vectors as determined by the `a` parameter.
+	fn mix(&self, rhs: Self, a: A) -> Self;
 }
 
 /// Exposes the step operation for use by the
 /// [`step`](../fn.step.html) function.
-pub trait HasStep {
+pub trait HasStep<E = Self> {
 	/// For each component, returns 0.0 if less than edge, 1.0 otherwise.
 	/// NOTE: GLSL function arguments are the other way around.
-	fn step(&self, edge: Self) -> Self;
+	fn step(&self, edge: E) -> Self;
 }
 
 /// Exposes the smooth step operation for use by the
 /// [`smoothstep`](../fn.smoothstep.html) function.
-pub trait HasSmoothStep {
+pub trait HasSmoothStep<E = Self> {
 	/// Returns 0.0 if less than edge0, 1.0 if greater than
 	/// edge1, and uses Hermite interpolation between the
 	/// values otherwise.
-	fn smoothstep(&self, edge0: Self, edge1: Self) -> Self;
+	fn smoothstep(&self, edge0: E, edge1: E) -> Self;
+}
+
+/// Exposes the [`isnan`](../fn.isnan.html) and
+/// [`isinf`](../fn.isinf.html) functions.
+pub trait HasFloatClass {
+	/// The boolean vector holding the results of a test.
+	type Output;
+
+	/// Returns, for each component, whether it is NaN.
+	fn is_nan(&self) -> Self::Output;
+	/// Returns, for each component, whether it is infinite.
+	fn is_inf(&self) -> Self::Output;
+}
+
+/// Exposes the [`frexp`](../fn.frexp.html) and
+/// [`ldexp`](../fn.ldexp.html) functions.
+pub trait HasFrexp: Sized {
+	/// The integer vector holding the exponents.
+	type Exponent;
+
+	/// Splits each component into a significand in [0.5, 1)
+	/// and a power of two exponent.
+	fn frexp(&self) -> (Self, Self::Exponent);
+	/// Multiplies each component by two raised to the
+	/// matching exponent.
+	fn ldexp(&self, exp: Self::Exponent) -> Self;
+}
+
+/// Exposes the fused multiply-add operation for use by
+/// the [`fma`](../fn.fma.html) function.
+pub trait HasFma {
+	/// Returns `self * b + c` for each component, computed
+	/// with a single rounding.
+	fn fma(&self, b: Self, c: Self) -> Self;
+}
+
+/// Exposes the component-wise comparisons used by the
+/// [`less_than`](../fn.less_than.html) family of functions.
+pub trait HasRelational {
+	/// The boolean vector holding the results of a comparison.
+	type Output;
+
+	/// Returns the component-wise comparison `self < rhs`.
+	fn less_than(&self, rhs: Self) -> Self::Output;
+	/// Returns the component-wise comparison `self <= rhs`.
+	fn less_than_equal(&self, rhs: Self) -> Self::Output;
+	/// Returns the component-wise comparison `self > rhs`.
+	fn greater_than(&self, rhs: Self) -> Self::Output;
+	/// Returns the component-wise comparison `self >= rhs`.
+	fn greater_than_equal(&self, rhs: Self) -> Self::Output;
+	/// Returns the component-wise comparison `self == rhs`.
+	fn equal(&self, rhs: Self) -> Self::Output;
+	/// Returns the component-wise comparison `self != rhs`.
+	fn not_equal(&self, rhs: Self) -> Self::Output;
+}
+
+/// Exposes the operations on boolean vectors used by the
+/// [`any`](../fn.any.html), [`all`](../fn.all.html) and
+/// [`not`](../fn.not.html) functions.
+pub trait HasBoolOps {
+	/// Returns true if any component of the vector is true.
+	fn any(&self) -> bool;
+	/// Returns true if all components of the vector are true.
+	fn all(&self) -> bool;
+	/// Returns the component-wise logical complement of the vector.
+	fn not(&self) -> Self;
 }
 
 pub trait Zero {
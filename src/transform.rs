@@ -0,0 +1,349 @@
+
+use vec3::*;
+use vec4::*;
+use mat3::*;
+use mat4::*;
+use nonsquare::*;
+use traits::*;
+use functions::{dot, cross};
+use svd::perpendicular;
+
+use std::ops::*;
+
+/// An affine transform in 3D, made up of a linear part and a
+/// translation. Points are transformed by `linear * p + translation`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Transform3<T: Sized> {
+	pub linear: Mat3<T>,
+	pub translation: Vec3<T>
+}
+
+impl<T: Sized> Transform3<T> {
+	pub fn new(linear: Mat3<T>, translation: Vec3<T>) -> Self {
+		Self { linear, translation }
+	}
+}
+
+impl<T: Sized + Copy + Zero + One> Transform3<T> {
+	/// Returns the identity transform.
+	pub fn identity() -> Self {
+		Self::new(Mat3::identity(), Vec3::zero())
+	}
+
+	/// Creates a transform that only translates.
+	pub fn from_translation(translation: Vec3<T>) -> Self {
+		Self::new(Mat3::identity(), translation)
+	}
+
+	/// Creates a transform with `rotation` as its linear part.
+	pub fn from_rotation(rotation: Mat3<T>) -> Self {
+		Self::new(rotation, Vec3::zero())
+	}
+
+	/// Creates a transform that scales along each axis.
+	pub fn from_scale(scale: Vec3<T>) -> Self {
+		let zero = T::zero();
+
+		Self::new(Mat3::from_cols([
+			vec3(scale.x, zero, zero),
+			vec3(zero, scale.y, zero),
+			vec3(zero, zero, scale.z)
+		]), Vec3::zero())
+	}
+}
+
+impl<T> Transform3<T>
+	where T: Sized + Copy + Zero + One + Mul<Output = T> + Add<Output = T>
+{
+	/// Creates a transform that scales, then rotates and then
+	/// translates.
+	pub fn from_translation_rotation_scale(translation: Vec3<T>, rotation: Mat3<T>, scale: Vec3<T>) -> Self {
+		Self::new(rotation * Self::from_scale(scale).linear, translation)
+	}
+
+	/// Applies the transform to a point.
+	pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+		self.linear * point + self.translation
+	}
+
+	/// Applies the transform to a direction. This ignores the
+	/// translation.
+	pub fn transform_vector(&self, vector: Vec3<T>) -> Vec3<T> {
+		self.linear * vector
+	}
+
+	/// Returns the 4x4 matrix of the transform.
+	pub fn to_mat4(&self) -> Mat4<T> {
+		let m = Mat4::from_mat3(self.linear);
+		let t = self.translation;
+
+		Mat4::from_cols([ m[0], m[1], m[2], vec4(t.x, t.y, t.z, T::one()) ])
+	}
+
+	/// Returns the transform as an affine matrix with three
+	/// rows and four columns (GLSL's `mat4x3`), the last column
+	/// being the translation.
+	pub fn to_mat4x3(&self) -> Mat4x3<T> {
+		let l = self.linear;
+
+		Mat4x3::from_cols([ l[0], l[1], l[2], self.translation ])
+	}
+}
+
+impl<T> Transform3<T>
+	where T: Sized + Copy + One + Neg<Output = T> + Mul<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Div<Output = T>
+{
+	/// Returns the inverse transform. The result is not finite
+	/// if the linear part is singular.
+	pub fn inverse(&self) -> Self {
+		let linear = self.linear.inverse();
+
+		Self::new(linear, -(linear * self.translation))
+	}
+}
+
+impl<T: Sized + Copy> Transform3<T> {
+	/// Creates a transform from the upper three rows of a 4x4
+	/// matrix. The last row is assumed to be `(0, 0, 0, 1)`.
+	pub fn from_mat4(m: Mat4<T>) -> Self {
+		let c = |i: usize| vec3(m[i].x, m[i].y, m[i].z);
+
+		Self::new(Mat3::from_cols([ c(0), c(1), c(2) ]), c(3))
+	}
+
+	/// Creates a transform from an affine matrix with three rows
+	/// and four columns, the last column being the translation.
+	pub fn from_mat4x3(m: Mat4x3<T>) -> Self {
+		Self::new(Mat3::from_cols([ m[0], m[1], m[2] ]), m[3])
+	}
+}
+
+/// A transform split into a translation, a rotation and a
+/// scale along each axis, as returned by `Transform3::decompose`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Decomposition<T: Sized> {
+	pub translation: Vec3<T>,
+	pub rotation: Mat3<T>,
+	pub scale: Vec3<T>,
+	/// False if the transform contained shear (or, for `Mat4`, a
+	/// projective part) that can't be represented, in which case
+	/// composing the parts gives back a different transform.
+	pub exact: bool
+}
+
+impl<T> Decomposition<T>
+	where T: Sized + Copy + Zero + One + Mul<Output = T> + Add<Output = T>
+{
+	/// Puts the parts back together into a transform that scales,
+	/// then rotates and then translates.
+	pub fn compose(&self) -> Transform3<T> {
+		Transform3::from_translation_rotation_scale(self.translation, self.rotation, self.scale)
+	}
+}
+
+impl<T> Transform3<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasAbs + HasSqrt + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Splits the transform into a translation, a rotation and a
+	/// per-axis scale. A reflection is returned as a negative
+	/// scale along the x axis so that `rotation` is always a
+	/// proper rotation. If the columns of the linear part are
+	/// not orthogonal to within `epsilon` the result is marked
+	/// as inexact and the rotation comes from orthogonalizing
+	/// the columns in order, dropping the shear.
+	///
+	/// An axis whose column is shorter than `epsilon`, after
+	/// removing its components along the previous axes, gets a
+	/// scale of zero and a rotation axis perpendicular to the
+	/// others.
+	pub fn decompose(&self, epsilon: T) -> Decomposition<T> {
+		let zero = T::zero();
+		let len = |v: Vec3<T>| dot(v, v).sqrt();
+		let mut axes = [None; 3];
+		let mut scale = [zero; 3];
+		let mut exact = true;
+
+		for (i, &col) in self.linear.cols.iter().enumerate() {
+			let n = len(col);
+			let mut c = col;
+
+			for &r in axes[..i].iter().flatten() {
+				let shear = dot(r, col);
+
+				exact = exact && (n <= epsilon || shear.abs() <= epsilon * n);
+				c = c - r * shear;
+			}
+
+			let s = len(c);
+			if s > epsilon {
+				axes[i] = Some(c / s);
+				scale[i] = s;
+			}
+		}
+
+		let [r0, r1, r2] = match axes {
+			[Some(x), Some(y), Some(z)] => [x, y, z],
+			[Some(x), Some(y), None] => [x, y, cross(x, y)],
+			[Some(x), None, Some(z)] => [x, cross(z, x), z],
+			[None, Some(y), Some(z)] => [cross(y, z), y, z],
+			[Some(x), None, None] => {
+				let y = perpendicular(x);
+				[x, y, cross(x, y)]
+			}
+			[None, Some(y), None] => {
+				let z = perpendicular(y);
+				[cross(y, z), y, z]
+			}
+			[None, None, Some(z)] => {
+				let x = perpendicular(z);
+				[x, cross(z, x), z]
+			}
+			[None, None, None] => Mat3::identity().cols
+		};
+
+		// Only a full set of orthogonalized columns can form a
+		// left-handed basis; the filled in axes are always
+		// right-handed.
+		let rotation = Mat3::from_cols([ r0, r1, r2 ]);
+		let (rotation, sx) = if rotation.determinant() < zero {
+			(Mat3::from_cols([ -r0, r1, r2 ]), -scale[0])
+		}
+		else {
+			(rotation, scale[0])
+		};
+
+		Decomposition {
+			translation: self.translation,
+			rotation,
+			scale: vec3(sx, scale[1], scale[2]),
+			exact
+		}
+	}
+}
+
+impl<T> Mat4<T>
+	where T: Sized + Copy + Zero + One + PartialOrd + HasAbs + HasSqrt + Neg<Output = T>
+		+ Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+{
+	/// Splits an affine matrix into a translation, a rotation and
+	/// a per-axis scale, see `Transform3::decompose`. The result
+	/// is also marked inexact if the last row isn't `(0, 0, 0, 1)`
+	/// to within `epsilon`.
+	pub fn decompose(&self, epsilon: T) -> Decomposition<T> {
+		let mut parts = Transform3::from_mat4(*self).decompose(epsilon);
+		let last = self.row(3);
+
+		parts.exact = parts.exact
+			&& last.x.abs() <= epsilon
+			&& last.y.abs() <= epsilon
+			&& last.z.abs() <= epsilon
+			&& (last.w - T::one()).abs() <= epsilon;
+		parts
+	}
+}
+
+impl<T> Mul for Transform3<T>
+	where T: Copy + Sized + Mul<Output = T> + Add<Output = T>
+{
+	type Output = Self;
+
+	/// Composes two transforms. The result applies `rhs`
+	/// first and then `self`.
+	fn mul(self, rhs: Self) -> Self {
+		Self::new(
+			self.linear * rhs.linear,
+			self.linear * rhs.translation + self.translation)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EPS: f64 = 1e-9;
+
+	fn assert_vec_close(a: Vec3<f64>, b: Vec3<f64>) {
+		let d = a - b;
+
+		assert!(dot(d, d).sqrt() < EPS, "{:?} != {:?}", a, b);
+	}
+
+	fn assert_close(a: Mat3<f64>, b: Mat3<f64>) {
+		for c in 0..3 {
+			assert_vec_close(a[c], b[c]);
+		}
+	}
+
+	#[test]
+	fn decompose_round_trip() {
+		let scales = [
+			vec3(2.0, 1.0, 0.5),
+			vec3(-1.5, 3.0, 0.25),
+			vec3(1.5, -2.0, 0.5),
+			vec3(1.5, 2.0, -0.5),
+			vec3(-1.0, -2.0, 0.75),
+			vec3(1.0, 0.0, 2.0),
+			vec3(0.0, -2.0, 0.5),
+			vec3(1.5, 2.0, 0.0),
+			vec3(0.0, 0.0, -1.5),
+			vec3(0.0, 0.0, 0.0)
+		];
+		let rotations = [
+			Mat3::from_axis_angle(vec3(1.0, 2.0, 0.5), 1.1),
+			Mat3::from_axis_angle(vec3(0.0, 0.0, 1.0), 3.0),
+			Mat3::from_axis_angle(vec3(-1.0, 0.3, 0.2), -2.0)
+		];
+		let t = vec3(3.0, -1.0, 2.0);
+
+		for &r in &rotations {
+			for &s in &scales {
+				let a = Transform3::from_translation_rotation_scale(t, r, s);
+				let d = a.decompose(EPS);
+				let b = d.compose();
+
+				assert!(d.exact);
+				assert!((d.rotation.determinant() - 1.0).abs() < EPS);
+				assert_close(b.linear, a.linear);
+				assert_vec_close(b.translation, t);
+
+				let d = a.to_mat4().decompose(EPS);
+
+				assert!(d.exact);
+				assert_close(d.compose().linear, a.linear);
+			}
+		}
+	}
+
+	#[test]
+	fn decompose_shear_is_inexact() {
+		let shear = Mat3::from_cols_array([
+			1.0, 0.0, 0.0,
+			0.5, 1.0, 0.0,
+			0.0, 0.0, 1.0
+		]);
+		let a = Transform3::new(Mat3::from_axis_angle(vec3(1.0, 2.0, 0.5), 1.1) * shear, vec3(1.0, 2.0, 3.0));
+		let d = a.decompose(EPS);
+
+		assert!(!d.exact);
+		assert!((d.rotation.determinant() - 1.0).abs() < EPS);
+
+		// The second column is parallel to the first, so it can't
+		// be represented by a zero scale either.
+		let flat = Mat3::from_cols([ vec3(1.0, 2.0, 0.0), vec3(2.0, 4.0, 0.0), vec3(0.0, 0.0, 1.0) ]);
+		let d = Transform3::new(flat, Vec3::zero()).decompose(EPS);
+
+		assert!(!d.exact);
+		assert!((d.rotation.determinant() - 1.0).abs() < EPS);
+	}
+
+	#[test]
+	fn decompose_projective_mat4_is_inexact() {
+		let m = Transform3::from_translation(vec3(1.0, 2.0, 3.0)).to_mat4();
+		let p = Mat4::from_rows([ m.row(0), m.row(1), m.row(2), vec4(0.0, 0.0, 0.5, 1.0) ]);
+
+		assert!(m.decompose(EPS).exact);
+		assert!(!p.decompose(EPS).exact);
+	}
+}
@@ -10,6 +10,13 @@ pub struct Vec2<T: Sized>{
 	pub y: T
 }
 
+/// A 2D vector of signed integers (`ivec2` in GLSL).
+pub type IVec2 = Vec2<i32>;
+/// A 2D vector of unsigned integers (`uvec2` in GLSL).
+pub type UVec2 = Vec2<u32>;
+/// A 2D vector of booleans (`bvec2` in GLSL).
+pub type BVec2 = Vec2<bool>;
+
 /// Constructs a Vec2 from individual components.
 pub fn vec2<T: Sized + Clone>(x: T, y: T) -> Vec2<T> {
 	Vec2{ x, y }
@@ -213,6 +220,12 @@ impl<T> Rem for Vec2<T>
 	}
 }
 
+impl<T: Sized + Clone + Zero> Zero for Vec2<T> {
+	fn zero() -> Self {
+		Vec2::new([T::zero(), T::zero()])
+	}
+}
+
 impl<T> HasDot for Vec2<T>
 	where T: Mul<Output = T> + Add<Output = T> + Sized + Clone
 {
@@ -302,11 +315,56 @@ impl<T> DivAssign<T> for Vec2<T>
 	}
 }
 
+impl<T> HasMod<T> for Vec2<T>
+	where T: HasMod + Sized + Clone
+{
+	fn modulus(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.modulus(rhs.clone()))
+	}
+}
+
+impl<T> HasMix for Vec2<T>
+	where T: HasMix + Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Self) -> Self {
+		vec2(
+			self.x.mix(rhs.x, a.x),
+			self.y.mix(rhs.y, a.y))
+	}
+}
+impl<T> HasMix<Vec2<bool>> for Vec2<T>
+	where T: Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Vec2<bool>) -> Self {
+		vec2(
+			if a.x { rhs.x } else { self.x.clone() },
+			if a.y { rhs.y } else { self.y.clone() })
+	}
+}
+
+impl<T> HasMinMax<T> for Vec2<T>
+	where T: HasMinMax + Sized + Clone
+{
+	fn min(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.min(rhs.clone()))
+	}
+	fn max(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.max(rhs.clone()))
+	}
+}
+
 impl<T> HasClamp for Vec2<T>
 	where T: HasMinMax + Sized + Copy
 {
-	type ElemType = T;
-
+	fn clamp(&self, min: Self, max: Self) -> Self {
+		vec2(
+			self.x.min(max.x).max(min.x),
+			self.y.min(max.y).max(min.y))
+	}
+}
+impl<T> HasClamp<T> for Vec2<T>
+	where T: HasMinMax + Sized + Copy
+{
 	fn clamp(&self, min: T, max: T) -> Self {
 		vec2(
 			self.x.min(max).max(min),
@@ -314,3 +372,137 @@ impl<T> HasClamp for Vec2<T>
 	}
 }
 
+impl<T> HasStep<T> for Vec2<T>
+	where T: HasStep + Sized + Clone
+{
+	fn step(&self, edge: T) -> Self {
+		self.apply_op(|x| x.step(edge.clone()))
+	}
+}
+
+impl<T> HasSmoothStep for Vec2<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+		vec2(
+			self.x.smoothstep(edge0.x, edge1.x),
+			self.y.smoothstep(edge0.y, edge1.y))
+	}
+}
+impl<T> HasSmoothStep<T> for Vec2<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: T, edge1: T) -> Self {
+		vec2(
+			self.x.smoothstep(edge0, edge1),
+			self.y.smoothstep(edge0, edge1))
+	}
+}
+
+impl<T> HasFloatClass for Vec2<T>
+	where T: HasFloatClass<Output = bool> + Sized + Clone
+{
+	type Output = Vec2<bool>;
+
+	fn is_nan(&self) -> Vec2<bool> {
+		vec2(
+			self.x.is_nan(),
+			self.y.is_nan())
+	}
+	fn is_inf(&self) -> Vec2<bool> {
+		vec2(
+			self.x.is_inf(),
+			self.y.is_inf())
+	}
+}
+
+impl<T> HasModf for Vec2<T>
+	where T: HasModf + Sized + Clone
+{
+	fn modf(&self) -> (Self, Self) {
+		let (fx, wx) = self.x.modf();
+		let (fy, wy) = self.y.modf();
+
+		(vec2(fx, fy), vec2(wx, wy))
+	}
+}
+
+impl<T> HasFrexp for Vec2<T>
+	where T: HasFrexp + Sized + Clone,
+		  T::Exponent: Sized + Clone
+{
+	type Exponent = Vec2<T::Exponent>;
+
+	fn frexp(&self) -> (Self, Vec2<T::Exponent>) {
+		let (x, ex) = self.x.frexp();
+		let (y, ey) = self.y.frexp();
+
+		(vec2(x, y), vec2(ex, ey))
+	}
+	fn ldexp(&self, exp: Vec2<T::Exponent>) -> Self {
+		vec2(
+			self.x.ldexp(exp.x),
+			self.y.ldexp(exp.y))
+	}
+}
+
+impl<T> HasFma for Vec2<T>
+	where T: HasFma + Sized + Clone
+{
+	fn fma(&self, b: Self, c: Self) -> Self {
+		vec2(
+			self.x.fma(b.x, c.x),
+			self.y.fma(b.y, c.y))
+	}
+}
+
+impl<T> HasRelational for Vec2<T>
+	where T: PartialOrd + Sized + Clone
+{
+	type Output = Vec2<bool>;
+
+	fn less_than(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x < rhs.x,
+			self.y < rhs.y)
+	}
+	fn less_than_equal(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x <= rhs.x,
+			self.y <= rhs.y)
+	}
+	fn greater_than(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x > rhs.x,
+			self.y > rhs.y)
+	}
+	fn greater_than_equal(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x >= rhs.x,
+			self.y >= rhs.y)
+	}
+	fn equal(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x == rhs.x,
+			self.y == rhs.y)
+	}
+	fn not_equal(&self, rhs: Self) -> Vec2<bool> {
+		vec2(
+			self.x != rhs.x,
+			self.y != rhs.y)
+	}
+}
+
+impl HasBoolOps for Vec2<bool> {
+	fn any(&self) -> bool {
+		self.x || self.y
+	}
+	fn all(&self) -> bool {
+		self.x && self.y
+	}
+	fn not(&self) -> Self {
+		vec2(
+			!self.x,
+			!self.y)
+	}
+}
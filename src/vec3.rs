@@ -11,6 +11,13 @@ pub struct Vec3<T: Sized>{
 	pub z: T
 }
 
+/// A 3D vector of signed integers (`ivec3` in GLSL).
+pub type IVec3 = Vec3<i32>;
+/// A 3D vector of unsigned integers (`uvec3` in GLSL).
+pub type UVec3 = Vec3<u32>;
+/// A 3D vector of booleans (`bvec3` in GLSL).
+pub type BVec3 = Vec3<bool>;
+
 /// Constructs a Vec3 from individual components.
 pub fn vec3<T: Sized + Clone>(x: T, y: T, z: T) -> Vec3<T> {
 	Vec3::new([x, y, z])
@@ -234,6 +241,12 @@ impl<T> Rem for Vec3<T>
 	}
 }
 
+impl<T: Sized + Clone + Zero> Zero for Vec3<T> {
+	fn zero() -> Self {
+		Vec3::new([T::zero(), T::zero(), T::zero()])
+	}
+}
+
 impl<T> HasDot for Vec3<T>
 	where T: Mul<Output = T> + Add<Output = T> + Sized + Clone
 {
@@ -343,11 +356,59 @@ impl<T> DivAssign<T> for Vec3<T>
 	}
 }
 
+impl<T> HasMod<T> for Vec3<T>
+	where T: HasMod + Sized + Clone
+{
+	fn modulus(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.modulus(rhs.clone()))
+	}
+}
+
+impl<T> HasMix for Vec3<T>
+	where T: HasMix + Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Self) -> Self {
+		vec3(
+			self.x.mix(rhs.x, a.x),
+			self.y.mix(rhs.y, a.y),
+			self.z.mix(rhs.z, a.z))
+	}
+}
+impl<T> HasMix<Vec3<bool>> for Vec3<T>
+	where T: Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Vec3<bool>) -> Self {
+		vec3(
+			if a.x { rhs.x } else { self.x.clone() },
+			if a.y { rhs.y } else { self.y.clone() },
+			if a.z { rhs.z } else { self.z.clone() })
+	}
+}
+
+impl<T> HasMinMax<T> for Vec3<T>
+	where T: HasMinMax + Sized + Clone
+{
+	fn min(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.min(rhs.clone()))
+	}
+	fn max(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.max(rhs.clone()))
+	}
+}
+
 impl<T> HasClamp for Vec3<T>
 	where T: HasMinMax + Sized + Copy
 {
-	type ElemType = T;
-
+	fn clamp(&self, min: Self, max: Self) -> Self {
+		vec3(
+			self.x.min(max.x).max(min.x),
+			self.y.min(max.y).max(min.y),
+			self.z.min(max.z).max(min.z))
+	}
+}
+impl<T> HasClamp<T> for Vec3<T>
+	where T: HasMinMax + Sized + Copy
+{
 	fn clamp(&self, min: T, max: T) -> Self {
 		vec3(
 			self.x.min(max).max(min),
@@ -356,3 +417,152 @@ impl<T> HasClamp for Vec3<T>
 	}
 }
 
+impl<T> HasStep<T> for Vec3<T>
+	where T: HasStep + Sized + Clone
+{
+	fn step(&self, edge: T) -> Self {
+		self.apply_op(|x| x.step(edge.clone()))
+	}
+}
+
+impl<T> HasSmoothStep for Vec3<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+		vec3(
+			self.x.smoothstep(edge0.x, edge1.x),
+			self.y.smoothstep(edge0.y, edge1.y),
+			self.z.smoothstep(edge0.z, edge1.z))
+	}
+}
+impl<T> HasSmoothStep<T> for Vec3<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: T, edge1: T) -> Self {
+		vec3(
+			self.x.smoothstep(edge0, edge1),
+			self.y.smoothstep(edge0, edge1),
+			self.z.smoothstep(edge0, edge1))
+	}
+}
+
+impl<T> HasFloatClass for Vec3<T>
+	where T: HasFloatClass<Output = bool> + Sized + Clone
+{
+	type Output = Vec3<bool>;
+
+	fn is_nan(&self) -> Vec3<bool> {
+		vec3(
+			self.x.is_nan(),
+			self.y.is_nan(),
+			self.z.is_nan())
+	}
+	fn is_inf(&self) -> Vec3<bool> {
+		vec3(
+			self.x.is_inf(),
+			self.y.is_inf(),
+			self.z.is_inf())
+	}
+}
+
+impl<T> HasModf for Vec3<T>
+	where T: HasModf + Sized + Clone
+{
+	fn modf(&self) -> (Self, Self) {
+		let (fx, wx) = self.x.modf();
+		let (fy, wy) = self.y.modf();
+		let (fz, wz) = self.z.modf();
+
+		(vec3(fx, fy, fz), vec3(wx, wy, wz))
+	}
+}
+
+impl<T> HasFrexp for Vec3<T>
+	where T: HasFrexp + Sized + Clone,
+		  T::Exponent: Sized + Clone
+{
+	type Exponent = Vec3<T::Exponent>;
+
+	fn frexp(&self) -> (Self, Vec3<T::Exponent>) {
+		let (x, ex) = self.x.frexp();
+		let (y, ey) = self.y.frexp();
+		let (z, ez) = self.z.frexp();
+
+		(vec3(x, y, z), vec3(ex, ey, ez))
+	}
+	fn ldexp(&self, exp: Vec3<T::Exponent>) -> Self {
+		vec3(
+			self.x.ldexp(exp.x),
+			self.y.ldexp(exp.y),
+			self.z.ldexp(exp.z))
+	}
+}
+
+impl<T> HasFma for Vec3<T>
+	where T: HasFma + Sized + Clone
+{
+	fn fma(&self, b: Self, c: Self) -> Self {
+		vec3(
+			self.x.fma(b.x, c.x),
+			self.y.fma(b.y, c.y),
+			self.z.fma(b.z, c.z))
+	}
+}
+
+impl<T> HasRelational for Vec3<T>
+	where T: PartialOrd + Sized + Clone
+{
+	type Output = Vec3<bool>;
+
+	fn less_than(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x < rhs.x,
+			self.y < rhs.y,
+			self.z < rhs.z)
+	}
+	fn less_than_equal(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x <= rhs.x,
+			self.y <= rhs.y,
+			self.z <= rhs.z)
+	}
+	fn greater_than(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x > rhs.x,
+			self.y > rhs.y,
+			self.z > rhs.z)
+	}
+	fn greater_than_equal(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x >= rhs.x,
+			self.y >= rhs.y,
+			self.z >= rhs.z)
+	}
+	fn equal(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x == rhs.x,
+			self.y == rhs.y,
+			self.z == rhs.z)
+	}
+	fn not_equal(&self, rhs: Self) -> Vec3<bool> {
+		vec3(
+			self.x != rhs.x,
+			self.y != rhs.y,
+			self.z != rhs.z)
+	}
+}
+
+impl HasBoolOps for Vec3<bool> {
+	fn any(&self) -> bool {
+		self.x || self.y || self.z
+	}
+	fn all(&self) -> bool {
+		self.x && self.y && self.z
+	}
+	fn not(&self) -> Self {
+		vec3(
+			!self.x,
+			!self.y,
+			!self.z)
+	}
+}
@@ -12,6 +12,13 @@ pub struct Vec4<T: Sized>{
 	pub w: T
 }
 
+/// A 4D vector of signed integers (`ivec4` in GLSL).
+pub type IVec4 = Vec4<i32>;
+/// A 4D vector of unsigned integers (`uvec4` in GLSL).
+pub type UVec4 = Vec4<u32>;
+/// A 4D vector of booleans (`bvec4` in GLSL).
+pub type BVec4 = Vec4<bool>;
+
 /// Constructs a Vec4 from individual components.
 pub fn vec4<T: Sized + Clone>(x: T, y: T, z: T, w: T) -> Vec4<T> {
 	Vec4{ x, y, z, w }
@@ -255,6 +262,12 @@ impl<T> Rem for Vec4<T>
 	}
 }
 
+impl<T: Sized + Clone + Zero> Zero for Vec4<T> {
+	fn zero() -> Self {
+		Vec4::new([T::zero(), T::zero(), T::zero(), T::zero()])
+	}
+}
+
 impl<T> HasDot for Vec4<T>
 	where T: Mul<Output = T> + Add<Output = T> + Sized + Clone
 {
@@ -362,11 +375,62 @@ impl<T> DivAssign<T> for Vec4<T>
 	}
 }
 
+impl<T> HasMod<T> for Vec4<T>
+	where T: HasMod + Sized + Clone
+{
+	fn modulus(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.modulus(rhs.clone()))
+	}
+}
+
+impl<T> HasMix for Vec4<T>
+	where T: HasMix + Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Self) -> Self {
+		vec4(
+			self.x.mix(rhs.x, a.x),
+			self.y.mix(rhs.y, a.y),
+			self.z.mix(rhs.z, a.z),
+			self.w.mix(rhs.w, a.w))
+	}
+}
+impl<T> HasMix<Vec4<bool>> for Vec4<T>
+	where T: Sized + Clone
+{
+	fn mix(&self, rhs: Self, a: Vec4<bool>) -> Self {
+		vec4(
+			if a.x { rhs.x } else { self.x.clone() },
+			if a.y { rhs.y } else { self.y.clone() },
+			if a.z { rhs.z } else { self.z.clone() },
+			if a.w { rhs.w } else { self.w.clone() })
+	}
+}
+
+impl<T> HasMinMax<T> for Vec4<T>
+	where T: HasMinMax + Sized + Clone
+{
+	fn min(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.min(rhs.clone()))
+	}
+	fn max(&self, rhs: T) -> Self {
+		self.apply_op(|x| x.max(rhs.clone()))
+	}
+}
+
 impl<T> HasClamp for Vec4<T>
 	where T: HasMinMax + Sized + Copy
 {
-	type ElemType = T;
-
+	fn clamp(&self, min: Self, max: Self) -> Self {
+		vec4(
+			self.x.min(max.x).max(min.x),
+			self.y.min(max.y).max(min.y),
+			self.z.min(max.z).max(min.z),
+			self.w.min(max.w).max(min.w))
+	}
+}
+impl<T> HasClamp<T> for Vec4<T>
+	where T: HasMinMax + Sized + Copy
+{
 	fn clamp(&self, min: T, max: T) -> Self {
 		vec4(
 			self.x.min(max).max(min),
@@ -375,3 +439,168 @@ impl<T> HasClamp for Vec4<T>
 			self.w.min(max).max(min))
 	}
 }
+
+impl<T> HasStep<T> for Vec4<T>
+	where T: HasStep + Sized + Clone
+{
+	fn step(&self, edge: T) -> Self {
+		self.apply_op(|x| x.step(edge.clone()))
+	}
+}
+
+impl<T> HasSmoothStep for Vec4<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+		vec4(
+			self.x.smoothstep(edge0.x, edge1.x),
+			self.y.smoothstep(edge0.y, edge1.y),
+			self.z.smoothstep(edge0.z, edge1.z),
+			self.w.smoothstep(edge0.w, edge1.w))
+	}
+}
+impl<T> HasSmoothStep<T> for Vec4<T>
+	where T: HasSmoothStep + Sized + Copy
+{
+	fn smoothstep(&self, edge0: T, edge1: T) -> Self {
+		vec4(
+			self.x.smoothstep(edge0, edge1),
+			self.y.smoothstep(edge0, edge1),
+			self.z.smoothstep(edge0, edge1),
+			self.w.smoothstep(edge0, edge1))
+	}
+}
+
+impl<T> HasFloatClass for Vec4<T>
+	where T: HasFloatClass<Output = bool> + Sized + Clone
+{
+	type Output = Vec4<bool>;
+
+	fn is_nan(&self) -> Vec4<bool> {
+		vec4(
+			self.x.is_nan(),
+			self.y.is_nan(),
+			self.z.is_nan(),
+			self.w.is_nan())
+	}
+	fn is_inf(&self) -> Vec4<bool> {
+		vec4(
+			self.x.is_inf(),
+			self.y.is_inf(),
+			self.z.is_inf(),
+			self.w.is_inf())
+	}
+}
+
+impl<T> HasModf for Vec4<T>
+	where T: HasModf + Sized + Clone
+{
+	fn modf(&self) -> (Self, Self) {
+		let (fx, wx) = self.x.modf();
+		let (fy, wy) = self.y.modf();
+		let (fz, wz) = self.z.modf();
+		let (fw, ww) = self.w.modf();
+
+		(vec4(fx, fy, fz, fw), vec4(wx, wy, wz, ww))
+	}
+}
+
+impl<T> HasFrexp for Vec4<T>
+	where T: HasFrexp + Sized + Clone,
+		  T::Exponent: Sized + Clone
+{
+	type Exponent = Vec4<T::Exponent>;
+
+	fn frexp(&self) -> (Self, Vec4<T::Exponent>) {
+		let (x, ex) = self.x.frexp();
+		let (y, ey) = self.y.frexp();
+		let (z, ez) = self.z.frexp();
+		let (w, ew) = self.w.frexp();
+
+		(vec4(x, y, z, w), vec4(ex, ey, ez, ew))
+	}
+	fn ldexp(&self, exp: Vec4<T::Exponent>) -> Self {
+		vec4(
+			self.x.ldexp(exp.x),
+			self.y.ldexp(exp.y),
+			self.z.ldexp(exp.z),
+			self.w.ldexp(exp.w))
+	}
+}
+
+impl<T> HasFma for Vec4<T>
+	where T: HasFma + Sized + Clone
+{
+	fn fma(&self, b: Self, c: Self) -> Self {
+		vec4(
+			self.x.fma(b.x, c.x),
+			self.y.fma(b.y, c.y),
+			self.z.fma(b.z, c.z),
+			self.w.fma(b.w, c.w))
+	}
+}
+
+impl<T> HasRelational for Vec4<T>
+	where T: PartialOrd + Sized + Clone
+{
+	type Output = Vec4<bool>;
+
+	fn less_than(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x < rhs.x,
+			self.y < rhs.y,
+			self.z < rhs.z,
+			self.w < rhs.w)
+	}
+	fn less_than_equal(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x <= rhs.x,
+			self.y <= rhs.y,
+			self.z <= rhs.z,
+			self.w <= rhs.w)
+	}
+	fn greater_than(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x > rhs.x,
+			self.y > rhs.y,
+			self.z > rhs.z,
+			self.w > rhs.w)
+	}
+	fn greater_than_equal(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x >= rhs.x,
+			self.y >= rhs.y,
+			self.z >= rhs.z,
+			self.w >= rhs.w)
+	}
+	fn equal(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x == rhs.x,
+			self.y == rhs.y,
+			self.z == rhs.z,
+			self.w == rhs.w)
+	}
+	fn not_equal(&self, rhs: Self) -> Vec4<bool> {
+		vec4(
+			self.x != rhs.x,
+			self.y != rhs.y,
+			self.z != rhs.z,
+			self.w != rhs.w)
+	}
+}
+
+impl HasBoolOps for Vec4<bool> {
+	fn any(&self) -> bool {
+		self.x || self.y || self.z || self.w
+	}
+	fn all(&self) -> bool {
+		self.x && self.y && self.z && self.w
+	}
+	fn not(&self) -> Self {
+		vec4(
+			!self.x,
+			!self.y,
+			!self.z,
+			!self.w)
+	}
+}